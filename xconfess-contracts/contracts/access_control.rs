@@ -7,9 +7,11 @@
 //! ```text
 //!  OWNER  ──▶ assign_admin / revoke_admin / transfer_ownership / update_config
 //!   │
-//!   └──▶ ADMIN  ──▶ resolve
+//!   └──▶ ADMIN  ──▶ resolve / grant_operator / grant_guardian
 //! ```
 //!
+//! Guardians may pause (see `emergency_pause`) but never unpause.
+//!
 //! One owner exists at all times (set during `initialize`).
 //! Any number of admins may be active simultaneously.
 //! Owner is implicitly an admin for every privileged function.
@@ -48,6 +50,7 @@ pub enum AccessKey {
     Owner,
     Admins,
     Operators,
+    Guardians,
 }
 
 #[contracttype]
@@ -56,6 +59,7 @@ pub enum Role {
     Owner,
     Admin,
     Operator,
+    Guardian,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -90,6 +94,10 @@ pub enum AccessError {
     AlreadyOperator = 9,
     /// Target address is not an operator (cannot revoke) (code 10).
     NotOperator = 10,
+    /// Target address is already a guardian (code 11).
+    AlreadyGuardian = 11,
+    /// Target address is not a guardian (cannot revoke) (code 12).
+    NotGuardian = 12,
 }
 
 #[contractevent(topics = ["adm_grant"], data_format = "single-value")]
@@ -186,12 +194,23 @@ pub fn is_operator(env: &Env, addr: &Address) -> bool {
     operators.contains_key(addr.clone())
 }
 
+/// Returns `true` if `addr` is in the guardian set.
+pub fn is_guardian(env: &Env, addr: &Address) -> bool {
+    let guardians: Map<Address, ()> = env
+        .storage()
+        .instance()
+        .get(&AccessKey::Guardians)
+        .unwrap_or_else(|| Map::new(env));
+    guardians.contains_key(addr.clone())
+}
+
 /// Return true when `addr` has the requested role.
 pub fn has_role(env: &Env, addr: &Address, role: Role) -> Result<bool, AccessError> {
     match role {
         Role::Owner => is_owner(env, addr),
         Role::Admin => Ok(is_admin(env, addr)),
         Role::Operator => Ok(is_operator(env, addr)),
+        Role::Guardian => Ok(is_guardian(env, addr)),
     }
 }
 
@@ -442,3 +461,49 @@ pub fn revoke_operator(env: &Env, caller: &Address, target: &Address) -> Result<
 
     Ok(())
 }
+
+/// Grant `target` the guardian role (pause-only).
+/// Caller must be owner or admin.
+pub fn grant_guardian(env: &Env, caller: &Address, target: &Address) -> Result<(), AccessError> {
+    require_admin_or_owner(env, caller)?;
+
+    if is_guardian(env, target) {
+        return Err(AccessError::AlreadyGuardian);
+    }
+
+    let mut guardians: Map<Address, ()> = env
+        .storage()
+        .instance()
+        .get(&AccessKey::Guardians)
+        .unwrap_or_else(|| Map::new(env));
+
+    guardians.set(target.clone(), ());
+    env.storage()
+        .instance()
+        .set(&AccessKey::Guardians, &guardians);
+
+    Ok(())
+}
+
+/// Revoke `target`'s guardian role.
+/// Caller must be owner or admin.
+pub fn revoke_guardian(env: &Env, caller: &Address, target: &Address) -> Result<(), AccessError> {
+    require_admin_or_owner(env, caller)?;
+
+    if !is_guardian(env, target) {
+        return Err(AccessError::NotGuardian);
+    }
+
+    let mut guardians: Map<Address, ()> = env
+        .storage()
+        .instance()
+        .get(&AccessKey::Guardians)
+        .unwrap_or_else(|| Map::new(env));
+
+    guardians.remove(target.clone());
+    env.storage()
+        .instance()
+        .set(&AccessKey::Guardians, &guardians);
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Client for the confession-registry contract, used to resolve who may claim
+//! tips sent to a confession.
+//!
+//! The types mirror `confession_registry::{Confession, ConfessionStatus}`
+//! field for field so values decode across the contract boundary; keep them in
+//! sync with the registry. `cross_contract_harness` in confession-registry
+//! fails if they drift.
+
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, Symbol, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfessionStatus {
+    Active,
+    Deleted,
+    Flagged,
+    Expired,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Confession {
+    pub id: u64,
+    /// `None` until a pseudonymous author proves authorship.
+    pub author: Option<Address>,
+    pub author_commitment: Option<BytesN<32>>,
+    pub content_hash: BytesN<32>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub status: ConfessionStatus,
+    pub expires_at: Option<u64>,
+    pub labels: Vec<Symbol>,
+}
+
+#[allow(dead_code)] // Only the generated client is used.
+#[contractclient(name = "ConfessionRegistryClient")]
+pub trait ConfessionRegistry {
+    fn find_confession(env: Env, id: u64) -> Option<Confession>;
+}
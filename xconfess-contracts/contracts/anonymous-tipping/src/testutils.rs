@@ -0,0 +1,41 @@
+//! Helpers for tests of this contract and of contracts that tip through it.
+//! Built for this crate's tests and with the `testutils` feature.
+
+use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Address, BytesN, Env};
+
+use crate::{compute_refund_commitment, AnonymousTipping, AnonymousTippingClient};
+
+/// An environment with all auths mocked and a tipping contract initialized
+/// with a fresh Stellar Asset Contract token. Returns the contract address.
+pub fn setup() -> (Env, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AnonymousTipping, ());
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    AnonymousTippingClient::new(&env, &contract_id).init(&token);
+    (env, contract_id)
+}
+
+pub fn mk_client<'a>(env: &'a Env, id: &'a Address) -> AnonymousTippingClient<'a> {
+    AnonymousTippingClient::new(env, id)
+}
+
+/// A fresh sender holding `i128::MAX` of the tip token.
+pub fn tipper(env: &Env, client: &AnonymousTippingClient) -> Address {
+    let sender = Address::generate(env);
+    StellarAssetClient::new(env, &client.get_token().unwrap()).mint(&sender, &i128::MAX);
+    sender
+}
+
+/// Salt behind [`refund_commitment`].
+pub fn refund_salt(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0x5a; 32])
+}
+
+/// Refund commitment of `sender` under [`refund_salt`].
+pub fn refund_commitment(env: &Env, sender: &Address) -> BytesN<32> {
+    compute_refund_commitment(env, sender, &refund_salt(env))
+}
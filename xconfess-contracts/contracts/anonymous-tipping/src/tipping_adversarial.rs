@@ -8,26 +8,20 @@
 mod adversarial {
     extern crate std;
 
-    use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString};
-
-    // The #[contractimpl] macro emits `AnonymousTippingClient<'_>` alongside
-    // the contract struct at the crate root.
-    use crate::{AnonymousTipping, AnonymousTippingClient, Error};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token::{StellarAssetClient, TokenClient},
+        Address, BytesN, Env, String as SorobanString,
+    };
+
+    use crate::{
+        emergency_pause::{PauseAction, PauseOrigin, PauseScope, PauseTarget},
+        testutils::{mk_client, refund_commitment, setup, tipper},
+        AnonymousTipping, BreakerConfig, Error, TokenLimits,
+    };
 
     // ── helpers ──────────────────────────────────────────────────────────────
 
-    fn setup() -> (Env, Address) {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract_id = env.register(AnonymousTipping, ());
-        AnonymousTippingClient::new(&env, &contract_id).init();
-        (env, contract_id)
-    }
-
-    fn mk_client<'a>(env: &'a Env, id: &'a Address) -> AnonymousTippingClient<'a> {
-        AnonymousTippingClient::new(env, id)
-    }
-
     fn meta(env: &Env, len: usize) -> SorobanString {
         SorobanString::from_str(env, &std::string::String::from("m").repeat(len))
     }
@@ -38,8 +32,10 @@ mod adversarial {
     fn zero_amount_tip_rejected() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
-        let r = c.try_send_tip(&recipient, &0i128);
+        let r = c.try_send_tip(&tipper, &recipient, &token, &0i128);
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
@@ -49,8 +45,10 @@ mod adversarial {
     fn negative_one_amount_rejected() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
-        let r = c.try_send_tip(&recipient, &(-1i128));
+        let r = c.try_send_tip(&tipper, &recipient, &token, &(-1i128));
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
@@ -58,8 +56,10 @@ mod adversarial {
     fn negative_large_amount_rejected() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
-        let r = c.try_send_tip(&recipient, &(-1_000_000i128));
+        let r = c.try_send_tip(&tipper, &recipient, &token, &(-1_000_000i128));
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
@@ -67,8 +67,10 @@ mod adversarial {
     fn i128_min_amount_rejected() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
-        let r = c.try_send_tip(&recipient, &i128::MIN);
+        let r = c.try_send_tip(&tipper, &recipient, &token, &i128::MIN);
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
@@ -76,9 +78,11 @@ mod adversarial {
     fn zero_amount_with_proof_rejected() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let m = meta(&env, 10);
-        let r = c.try_send_tip_with_proof(&recipient, &0i128, &Some(m.clone()));
+        let r = c.try_send_tip_with_proof(&tipper, &recipient, &token, &0i128, &Some(m.clone()));
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
@@ -86,9 +90,12 @@ mod adversarial {
     fn negative_amount_with_proof_rejected() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let m = meta(&env, 10);
-        let r = c.try_send_tip_with_proof(&recipient, &(-42i128), &Some(m.clone()));
+        let r =
+            c.try_send_tip_with_proof(&tipper, &recipient, &token, &(-42i128), &Some(m.clone()));
         assert_eq!(r, Err(Ok(Error::InvalidTipAmount)));
     }
 
@@ -98,31 +105,37 @@ mod adversarial {
     fn metadata_empty_succeeds() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let m = meta(&env, 0);
-        let sid = c.send_tip_with_proof(&recipient, &1i128, &Some(m));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &token, &1i128, &Some(m));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 1);
+        assert_eq!(c.get_tips(&recipient, &token), 1);
     }
 
     #[test]
     fn metadata_64_bytes_succeeds() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let m = meta(&env, 64);
-        let sid = c.send_tip_with_proof(&recipient, &5i128, &Some(m));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &token, &5i128, &Some(m));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 5);
+        assert_eq!(c.get_tips(&recipient, &token), 5);
     }
 
     #[test]
     fn metadata_127_bytes_succeeds() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let m = meta(&env, 127);
-        let sid = c.send_tip_with_proof(&recipient, &1i128, &Some(m));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &token, &1i128, &Some(m));
         assert_eq!(sid, 1);
     }
 
@@ -130,23 +143,27 @@ mod adversarial {
     fn metadata_exactly_max_succeeds() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let m = meta(&env, AnonymousTipping::MAX_PROOF_METADATA_LEN as usize);
-        let sid = c.send_tip_with_proof(&recipient, &7i128, &Some(m));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &token, &7i128, &Some(m));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 7);
+        assert_eq!(c.get_tips(&recipient, &token), 7);
     }
 
     #[test]
     fn metadata_max_plus_one_rejected() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let m = meta(
             &env,
             (AnonymousTipping::MAX_PROOF_METADATA_LEN + 1) as usize,
         );
-        let r = c.try_send_tip_with_proof(&recipient, &1i128, &Some(m.clone()));
+        let r = c.try_send_tip_with_proof(&tipper, &recipient, &token, &1i128, &Some(m.clone()));
         assert_eq!(r, Err(Ok(Error::MetadataTooLong)));
     }
 
@@ -154,9 +171,11 @@ mod adversarial {
     fn metadata_256_bytes_rejected() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let m = meta(&env, 256);
-        let r = c.try_send_tip_with_proof(&recipient, &1i128, &Some(m.clone()));
+        let r = c.try_send_tip_with_proof(&tipper, &recipient, &token, &1i128, &Some(m.clone()));
         assert_eq!(r, Err(Ok(Error::MetadataTooLong)));
     }
 
@@ -164,9 +183,11 @@ mod adversarial {
     fn metadata_extremely_large_rejected() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let m = meta(&env, 1024);
-        let r = c.try_send_tip_with_proof(&recipient, &1i128, &Some(m.clone()));
+        let r = c.try_send_tip_with_proof(&tipper, &recipient, &token, &1i128, &Some(m.clone()));
         assert_eq!(r, Err(Ok(Error::MetadataTooLong)));
     }
 
@@ -176,12 +197,14 @@ mod adversarial {
     fn settlement_ids_are_monotonically_increasing() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let r1 = Address::generate(&env);
         let r2 = Address::generate(&env);
 
-        let id1 = c.send_tip(&r1, &1i128);
-        let id2 = c.send_tip(&r2, &1i128);
-        let id3 = c.send_tip(&r1, &1i128);
+        let id1 = c.send_tip(&tipper, &r1, &token, &1i128);
+        let id2 = c.send_tip(&tipper, &r2, &token, &1i128);
+        let id3 = c.send_tip(&tipper, &r1, &token, &1i128);
 
         assert_eq!(id1, 1);
         assert_eq!(id2, 2);
@@ -195,27 +218,31 @@ mod adversarial {
     fn same_recipient_tips_accumulate() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
 
-        c.send_tip(&recipient, &10i128);
-        c.send_tip(&recipient, &25i128);
-        c.send_tip(&recipient, &5i128);
+        c.send_tip(&tipper, &recipient, &token, &10i128);
+        c.send_tip(&tipper, &recipient, &token, &25i128);
+        c.send_tip(&tipper, &recipient, &token, &5i128);
 
-        assert_eq!(c.get_tips(&recipient), 40);
+        assert_eq!(c.get_tips(&recipient, &token), 40);
     }
 
     #[test]
     fn multiple_recipients_are_independent() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let r1 = Address::generate(&env);
         let r2 = Address::generate(&env);
 
-        c.send_tip(&r1, &100i128);
-        c.send_tip(&r2, &200i128);
+        c.send_tip(&tipper, &r1, &token, &100i128);
+        c.send_tip(&tipper, &r2, &token, &200i128);
 
-        assert_eq!(c.get_tips(&r1), 100);
-        assert_eq!(c.get_tips(&r2), 200);
+        assert_eq!(c.get_tips(&r1, &token), 100);
+        assert_eq!(c.get_tips(&r2, &token), 200);
     }
 
     // ── volume smoke: 10 sequential tips ─────────────────────────────────────
@@ -224,9 +251,11 @@ mod adversarial {
     fn ten_sequential_tips_nonce_matches_count() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         for i in 1_u64..=10 {
             let recipient = Address::generate(&env);
-            let sid = c.send_tip(&recipient, &(i as i128));
+            let sid = c.send_tip(&tipper, &recipient, &token, &(i as i128));
             assert_eq!(sid, i);
         }
         assert_eq!(c.latest_settlement_nonce(), 10);
@@ -238,45 +267,244 @@ mod adversarial {
     fn double_init_is_safe() {
         let (env, id) = setup(); // already calls init once
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
-        c.send_tip(&recipient, &1i128);
+        c.send_tip(&tipper, &recipient, &token, &1i128);
 
-        c.init(); // second init must not reset state
+        c.init(&Address::generate(&env)); // second init must not reset state
 
-        assert_eq!(c.get_tips(&recipient), 1);
+        assert_eq!(c.get_tips(&recipient, &token), 1);
         assert_eq!(c.latest_settlement_nonce(), 1);
+        assert_eq!(c.get_token(), Some(token.clone()));
+        assert_eq!(c.get_allowed_tokens(), soroban_sdk::vec![&env, token]);
     }
 
     // ── send_tip vs send_tip_with_proof(None) equivalence ────────────────────
 
     #[test]
     fn send_tip_and_proof_none_produce_equal_totals() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
+
+        let r1 = Address::generate(&env);
+        let r2 = Address::generate(&env);
+
+        c.send_tip(&tipper, &r1, &token, &42i128);
+        c.send_tip_with_proof(&tipper, &r2, &token, &42i128, &None);
+
+        assert_eq!(c.get_tips(&r1, &token), c.get_tips(&r2, &token));
+    }
+
+    // ── uninitialised contract has no token ──────────────────────────────────
+
+    #[test]
+    fn tip_without_init_rejects_every_token() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register(AnonymousTipping, ());
         let c = mk_client(&env, &contract_id);
-        c.init();
+        let token = Address::generate(&env);
+        let sender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        assert_eq!(c.get_token(), None);
+        let r = c.try_send_tip(&sender, &recipient, &token, &3i128);
+        assert_eq!(r, Err(Ok(Error::UnsupportedToken)));
+        assert_eq!(c.latest_settlement_nonce(), 0);
+    }
 
-        let r1 = Address::generate(&env);
-        let r2 = Address::generate(&env);
+    #[test]
+    fn legacy_contract_binds_token_once_by_owner() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(AnonymousTipping, ());
+        let c = mk_client(&env, &contract_id);
+        let owner = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        c.configure_controls(&owner, &100, &60);
+
+        assert_eq!(
+            c.try_set_token(&outsider, &token),
+            Err(Ok(Error::Unauthorized))
+        );
+        c.set_token(&owner, &token);
+        assert_eq!(c.get_token(), Some(token.clone()));
+        assert_eq!(
+            c.try_set_token(&owner, &Address::generate(&env)),
+            Err(Ok(Error::TokenAlreadyConfigured))
+        );
+
+        let tipper = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        assert_eq!(c.send_tip(&tipper, &recipient, &token, &3i128), 1);
+        assert_eq!(c.get_claimable(&recipient, &token), 3);
+    }
+
+    // ── token allowlist ───────────────────────────────────────────────────────
+
+    #[test]
+    fn allowlisted_tokens_settle_and_total_separately() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        c.configure_controls(&owner, &100, &60);
+        let xlm = c.get_token().unwrap();
+        let usdc = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        c.allow_token(&owner, &usdc, &10, &1_000);
+        assert_eq!(
+            c.get_allowed_tokens(),
+            soroban_sdk::vec![&env, xlm.clone(), usdc.clone()]
+        );
+
+        let sender = tipper(&env, &c);
+        StellarAssetClient::new(&env, &usdc).mint(&sender, &1_000);
+        let recipient = Address::generate(&env);
+        c.send_tip(&sender, &recipient, &xlm, &5i128);
+        c.send_tip(&sender, &recipient, &usdc, &40i128);
+        c.send_tip(&sender, &recipient, &usdc, &60i128);
+
+        assert_eq!(c.get_tips(&recipient, &xlm), 5);
+        assert_eq!(c.get_tips(&recipient, &usdc), 100);
+        assert_eq!(c.get_claimable(&recipient, &xlm), 5);
+        assert_eq!(c.get_claimable(&recipient, &usdc), 100);
+        assert_eq!(TokenClient::new(&env, &usdc).balance(&id), 100);
+        assert_eq!(c.latest_settlement_nonce(), 3);
+    }
+
+    #[test]
+    fn tip_outside_token_limits_is_rejected() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        c.configure_controls(&owner, &100, &60);
+        let token = c.get_token().unwrap();
+        c.allow_token(&owner, &token, &10, &100);
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+
+        for amount in [9i128, 101] {
+            assert_eq!(
+                c.try_send_tip(&sender, &recipient, &token, &amount),
+                Err(Ok(Error::AmountOutOfRange))
+            );
+        }
+        c.send_tip(&sender, &recipient, &token, &10i128);
+        c.send_tip(&sender, &recipient, &token, &100i128);
+        assert_eq!(c.get_tips(&recipient, &token), 110);
+    }
+
+    #[test]
+    fn unlisted_or_removed_token_is_rejected() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        c.configure_controls(&owner, &100, &60);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        let other = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+
+        assert_eq!(
+            c.try_send_tip(&sender, &recipient, &other, &1i128),
+            Err(Ok(Error::UnsupportedToken))
+        );
+
+        c.send_tip(&sender, &recipient, &token, &7i128);
+        c.remove_token(&owner, &token);
+        assert_eq!(c.get_token_limits(&token), None);
+        assert_eq!(c.get_allowed_tokens().len(), 0);
+        assert_eq!(
+            c.try_send_tip(&sender, &recipient, &token, &1i128),
+            Err(Ok(Error::UnsupportedToken))
+        );
+        assert_eq!(c.get_tips(&recipient, &token), 7);
+        assert_eq!(
+            c.try_remove_token(&owner, &token),
+            Err(Ok(Error::UnsupportedToken))
+        );
+    }
+
+    #[test]
+    fn allowlist_is_owner_only_validated_and_bounded() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        c.configure_controls(&owner, &100, &60);
+        let token = Address::generate(&env);
 
-        c.send_tip(&r1, &42i128);
-        c.send_tip_with_proof(&r2, &42i128, &None);
+        assert_eq!(
+            c.try_allow_token(&outsider, &token, &1, &10),
+            Err(Ok(Error::Unauthorized))
+        );
+        for (min, max) in [(0i128, 10i128), (-5, 10), (11, 10)] {
+            assert_eq!(
+                c.try_allow_token(&owner, &token, &min, &max),
+                Err(Ok(Error::InvalidTokenLimits))
+            );
+        }
 
-        assert_eq!(c.get_tips(&r1), c.get_tips(&r2));
+        // `init` already allowlisted one token.
+        for _ in 1..AnonymousTipping::MAX_ALLOWED_TOKENS {
+            c.allow_token(&owner, &Address::generate(&env), &1, &10);
+        }
+        assert_eq!(
+            c.try_allow_token(&owner, &token, &1, &10),
+            Err(Ok(Error::TooManyTokens))
+        );
+        // Updating an allowlisted token's limits does not need a free slot.
+        let first = c.get_token().unwrap();
+        c.allow_token(&owner, &first, &2, &20);
+        assert_eq!(
+            c.get_token_limits(&first),
+            Some(TokenLimits {
+                min_amount: 2,
+                max_amount: 20
+            })
+        );
     }
 
-    // ── uninitialised contract still works ───────────────────────────────────
+    // ── sender funds and auth ─────────────────────────────────────────────────
+
+    #[test]
+    fn tip_exceeding_sender_balance_fails_without_settling() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        StellarAssetClient::new(&env, &c.get_token().unwrap()).mint(&sender, &10);
+
+        assert!(c
+            .try_send_tip(&sender, &recipient, &token, &11i128)
+            .is_err());
+        assert_eq!(c.get_tips(&recipient, &token), 0);
+        assert_eq!(c.latest_settlement_nonce(), 0);
+    }
 
     #[test]
-    fn tip_without_explicit_init_still_succeeds() {
+    fn tip_requires_sender_auth() {
         let env = Env::default();
         let contract_id = env.register(AnonymousTipping, ());
         let c = mk_client(&env, &contract_id);
-        // No c.init() call — storage defaults to 0 via `unwrap_or`
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        c.init(&token);
+        let sender = Address::generate(&env);
         let recipient = Address::generate(&env);
-        let sid = c.send_tip(&recipient, &3i128);
-        assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 3);
+
+        assert!(c.try_send_tip(&sender, &recipient, &token, &1i128).is_err());
+        assert_eq!(c.latest_settlement_nonce(), 0);
     }
 
     // ── unknown recipient returns zero ────────────────────────────────────────
@@ -285,8 +513,9 @@ mod adversarial {
     fn get_tips_returns_zero_for_unknown_recipient() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
         let unknown = Address::generate(&env);
-        assert_eq!(c.get_tips(&unknown), 0);
+        assert_eq!(c.get_tips(&unknown, &token), 0);
     }
 
     // ── large and small valid amounts ─────────────────────────────────────────
@@ -295,21 +524,25 @@ mod adversarial {
     fn very_large_valid_amount_succeeds() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
         let amount: i128 = 1_000_000_000_000;
-        let sid = c.send_tip(&recipient, &amount);
+        let sid = c.send_tip(&tipper, &recipient, &token, &amount);
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), amount);
+        assert_eq!(c.get_tips(&recipient, &token), amount);
     }
 
     #[test]
     fn minimum_valid_amount_one_succeeds() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
-        let sid = c.send_tip(&recipient, &1i128);
+        let sid = c.send_tip(&tipper, &recipient, &token, &1i128);
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 1);
+        assert_eq!(c.get_tips(&recipient, &token), 1);
     }
 
     // ── overflow edge cases ─────────────────────────────────────────────────────
@@ -318,13 +551,15 @@ mod adversarial {
     fn total_overflow_returns_error() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
 
         // Send a tip that brings total to near max
-        c.send_tip(&recipient, &(i128::MAX - 100));
+        c.send_tip(&tipper, &recipient, &token, &(i128::MAX - 100));
 
         // Next tip should overflow
-        let r = c.try_send_tip(&recipient, &200i128);
+        let r = c.try_send_tip(&tipper, &recipient, &token, &200i128);
         assert_eq!(r, Err(Ok(Error::TotalOverflow)));
     }
 
@@ -332,6 +567,8 @@ mod adversarial {
     fn nonce_overflow_returns_error() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
 
         // Simulate reaching near max nonce by setting it manually
@@ -342,7 +579,7 @@ mod adversarial {
         });
 
         // Next tip should overflow nonce
-        let r = c.try_send_tip(&recipient, &1i128);
+        let r = c.try_send_tip(&tipper, &recipient, &token, &1i128);
         assert_eq!(r, Err(Ok(Error::NonceOverflow)));
     }
 
@@ -352,30 +589,34 @@ mod adversarial {
     fn metadata_unicode_succeeds() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
 
         // Test with Unicode characters (emoji, Chinese, etc.)
         let unicode_str = "🚀💰测试🔥";
         let metadata = SorobanString::from_str(&env, unicode_str);
 
-        let sid = c.send_tip_with_proof(&recipient, &5i128, &Some(metadata));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &token, &5i128, &Some(metadata));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 5);
+        assert_eq!(c.get_tips(&recipient, &token), 5);
     }
 
     #[test]
     fn metadata_whitespace_succeeds() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
 
         // Test with various whitespace characters
         let whitespace_str = " \t\n\r ";
         let metadata = SorobanString::from_str(&env, whitespace_str);
 
-        let sid = c.send_tip_with_proof(&recipient, &3i128, &Some(metadata));
+        let sid = c.send_tip_with_proof(&tipper, &recipient, &token, &3i128, &Some(metadata));
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), 3);
+        assert_eq!(c.get_tips(&recipient, &token), 3);
     }
 
     // ── amount precision tests ───────────────────────────────────────────────────
@@ -384,19 +625,23 @@ mod adversarial {
     fn max_valid_amount_succeeds() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
 
         // Test with maximum valid amount (less than would cause overflow)
         let max_amount = i128::MAX / 2;
-        let sid = c.send_tip(&recipient, &max_amount);
+        let sid = c.send_tip(&tipper, &recipient, &token, &max_amount);
         assert_eq!(sid, 1);
-        assert_eq!(c.get_tips(&recipient), max_amount);
+        assert_eq!(c.get_tips(&recipient, &token), max_amount);
     }
 
     #[test]
     fn pause_blocks_state_changing_tip_calls() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let owner = Address::generate(&env);
         let recipient = Address::generate(&env);
 
@@ -404,27 +649,239 @@ mod adversarial {
         c.pause(&owner, &SorobanString::from_str(&env, "incident"));
         assert!(c.is_paused());
         assert_eq!(
-            c.try_send_tip(&recipient, &1),
+            c.try_send_tip(&tipper, &recipient, &token, &1),
             Err(Ok(Error::ContractPaused))
         );
 
         c.unpause(&owner, &SorobanString::from_str(&env, "resolved"));
         assert!(!c.is_paused());
-        assert_eq!(c.send_tip(&recipient, &2), 1);
+        assert_eq!(c.send_tip(&tipper, &recipient, &token, &2), 1);
+    }
+
+    #[test]
+    fn tip_scope_pause_blocks_tips_but_not_withdrawals() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
+        let owner = Address::generate(&env);
+        let guardian = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let reason = SorobanString::from_str(&env, "spam");
+
+        c.configure_controls(&owner, &100, &60);
+        assert_eq!(
+            c.try_grant_guardian(&guardian, &guardian),
+            Err(Ok(Error::Unauthorized))
+        );
+        c.grant_guardian(&owner, &guardian);
+        assert_eq!(
+            c.try_grant_guardian(&owner, &guardian),
+            Err(Ok(Error::AlreadyGuardian))
+        );
+        c.send_tip(&tipper, &recipient, &token, &10);
+        let sub = c.subscribe(&tipper, &recipient, &token, &5, &60, &3);
+
+        assert_eq!(
+            c.try_pause_tipping(&guardian, &reason, &0),
+            Err(Ok(Error::InvalidPauseDuration))
+        );
+        c.pause_tipping(&guardian, &reason, &600);
+        assert!(c.is_tipping_paused());
+        assert!(!c.is_paused());
+        assert_eq!(
+            c.try_pause_tipping(&owner, &reason, &600),
+            Err(Ok(Error::AlreadyPaused))
+        );
+        assert_eq!(
+            c.try_send_tip(&tipper, &recipient, &token, &1),
+            Err(Ok(Error::ContractPaused))
+        );
+        assert_eq!(
+            c.try_send_tip_to_commitment(
+                &tipper,
+                &BytesN::from_array(&env, &[1; 32]),
+                &token,
+                &1,
+                &refund_commitment(&env, &tipper)
+            ),
+            Err(Ok(Error::ContractPaused))
+        );
+        assert_eq!(
+            c.try_subscribe(&tipper, &recipient, &token, &5, &60, &3),
+            Err(Ok(Error::ContractPaused))
+        );
+        assert_eq!(c.try_collect(&sub), Err(Ok(Error::ContractPaused)));
+        c.withdraw(&recipient, &token, &10);
+
+        assert_eq!(
+            c.try_unpause_tipping(&guardian, &reason),
+            Err(Ok(Error::Unauthorized))
+        );
+        c.unpause_tipping(&owner, &reason);
+        assert_eq!(
+            c.try_unpause_tipping(&owner, &reason),
+            Err(Ok(Error::NotPaused))
+        );
+        assert!(!c.is_tipping_paused());
+        c.send_tip(&tipper, &recipient, &token, &1);
+
+        let history = c.pause_history(&None, &10).items;
+        assert_eq!(history.len(), 2);
+        let paused = history.get(0).unwrap();
+        assert_eq!(paused.target, PauseTarget::Scope(PauseScope::Tip));
+        assert_eq!(
+            (paused.action, paused.actor),
+            (PauseAction::Pause, guardian)
+        );
+        assert_eq!(history.get(1).unwrap().action, PauseAction::Unpause);
     }
 
     #[test]
     fn per_wallet_rate_limit_throttles_predictably() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let owner = Address::generate(&env);
         let recipient = Address::generate(&env);
 
         c.configure_controls(&owner, &2, &60);
 
-        assert_eq!(c.send_tip(&recipient, &1), 1);
-        assert_eq!(c.send_tip(&recipient, &1), 2);
-        assert_eq!(c.try_send_tip(&recipient, &1), Err(Ok(Error::RateLimited)));
+        assert_eq!(c.send_tip(&tipper, &recipient, &token, &1), 1);
+        assert_eq!(c.send_tip(&tipper, &recipient, &token, &1), 2);
+        assert_eq!(
+            c.try_send_tip(&tipper, &recipient, &token, &1),
+            Err(Ok(Error::RateLimited))
+        );
+    }
+
+    #[test]
+    fn pair_cap_limits_one_sender_per_recipient() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let owner = Address::generate(&env);
+        let spammer = tipper(&env, &c);
+        let fan = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        c.configure_controls(&owner, &10, &60);
+        assert_eq!(c.get_pair_cap(), 0);
+        c.configure_pair_cap(&owner, &2);
+        assert_eq!(c.get_pair_cap(), 2);
+
+        c.send_tip(&spammer, &recipient, &token, &1);
+        c.send_tip(&spammer, &recipient, &token, &1);
+        assert_eq!(
+            c.try_send_tip(&spammer, &recipient, &token, &1),
+            Err(Ok(Error::PairRateLimited))
+        );
+
+        // The spammer can tip others, and others can tip the recipient.
+        c.send_tip(&spammer, &other, &token, &1);
+        c.send_tip(&fan, &recipient, &token, &1);
+        assert_eq!(c.get_tips(&recipient, &token), 3);
+
+        // The pair window resets with the rate-limit window.
+        env.ledger().with_mut(|li| li.timestamp += 60);
+        c.send_tip(&spammer, &recipient, &token, &1);
+
+        // Removing the cap lifts the pair limit.
+        c.configure_pair_cap(&owner, &0);
+        c.send_tip(&spammer, &recipient, &token, &1);
+        c.send_tip(&spammer, &recipient, &token, &1);
+        assert_eq!(c.get_tips(&recipient, &token), 6);
+    }
+
+    #[test]
+    fn pair_cap_is_owner_only() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        c.configure_controls(&owner, &10, &60);
+
+        assert_eq!(
+            c.try_configure_pair_cap(&outsider, &1),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(c.get_pair_cap(), 0);
+    }
+
+    #[test]
+    fn circuit_breaker_pauses_tipping_on_spike_then_lapses() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
+        let owner = Address::generate(&env);
+        let recipient = Address::generate(&env);
+
+        c.configure_controls(&owner, &100, &60);
+        c.configure_circuit_breaker(
+            &owner,
+            &BreakerConfig {
+                max_writes_per_window: 2,
+                window_ledgers: 10,
+                trip_duration_seconds: 600,
+            },
+        );
+
+        c.send_tip(&tipper, &recipient, &token, &1);
+        c.send_tip(&tipper, &recipient, &token, &1);
+        // The tip that crosses the threshold settles; the next one is refused.
+        assert_eq!(c.send_tip(&tipper, &recipient, &token, &1), 3);
+        assert!(c.is_tipping_paused());
+        assert!(!c.is_paused());
+        assert_eq!(
+            c.try_send_tip(&tipper, &recipient, &token, &1),
+            Err(Ok(Error::ContractPaused))
+        );
+        // The trip is a shared Tip scope pause, so withdrawals stay open.
+        c.withdraw(&recipient, &token, &3);
+
+        let trip = c.pause_history(&None, &10).items.get(0).unwrap();
+        assert_eq!(trip.target, PauseTarget::Scope(PauseScope::Tip));
+        assert_eq!(
+            (trip.origin, trip.actor, trip.expires_at),
+            (PauseOrigin::CircuitBreaker, id.clone(), Some(600))
+        );
+
+        env.ledger().with_mut(|l| l.timestamp += 600);
+        assert!(!c.is_tipping_paused());
+        assert_eq!(c.send_tip(&tipper, &recipient, &token, &1), 4);
+
+        // A second trip can be lifted early with unpause_tipping.
+        c.send_tip(&tipper, &recipient, &token, &1);
+        c.send_tip(&tipper, &recipient, &token, &1);
+        assert!(c.is_tipping_paused());
+        c.unpause_tipping(&owner, &SorobanString::from_str(&env, "resolved"));
+        assert_eq!(c.send_tip(&tipper, &recipient, &token, &1), 7);
+    }
+
+    #[test]
+    fn circuit_breaker_config_is_owner_only_and_validated() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        let config = BreakerConfig {
+            max_writes_per_window: 5,
+            window_ledgers: 0,
+            trip_duration_seconds: 600,
+        };
+
+        c.configure_controls(&owner, &100, &60);
+        assert_eq!(
+            c.try_configure_circuit_breaker(&outsider, &config),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            c.try_configure_circuit_breaker(&owner, &config),
+            Err(Ok(Error::InvalidBreakerConfig))
+        );
     }
 }
 
@@ -434,21 +891,9 @@ mod adversarial {
 mod replay_correlation {
     extern crate std;
 
-    use soroban_sdk::{testutils::Address as _, Address, Env};
-
-    use crate::{AnonymousTipping, AnonymousTippingClient};
+    use soroban_sdk::{testutils::Address as _, Address};
 
-    fn setup() -> (Env, Address) {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract_id = env.register(AnonymousTipping, ());
-        AnonymousTippingClient::new(&env, &contract_id).init();
-        (env, contract_id)
-    }
-
-    fn mk_client<'a>(env: &'a Env, id: &'a Address) -> AnonymousTippingClient<'a> {
-        AnonymousTippingClient::new(env, id)
-    }
+    use crate::testutils::{mk_client, setup, tipper};
 
     /// Each `send_tip` must return a strictly incrementing `settlement_id`.
     /// Backend consumers can use this to detect replayed events (same id = replay).
@@ -456,11 +901,13 @@ mod replay_correlation {
     fn settlement_ids_are_strictly_monotonic() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
 
-        let id1 = c.send_tip(&recipient, &10);
-        let id2 = c.send_tip(&recipient, &20);
-        let id3 = c.send_tip(&recipient, &30);
+        let id1 = c.send_tip(&tipper, &recipient, &token, &10);
+        let id2 = c.send_tip(&tipper, &recipient, &token, &20);
+        let id3 = c.send_tip(&tipper, &recipient, &token, &30);
 
         assert!(id2 > id1, "settlement_id must increment: {} > {}", id2, id1);
         assert!(id3 > id2, "settlement_id must increment: {} > {}", id3, id2);
@@ -473,10 +920,12 @@ mod replay_correlation {
     fn identical_tips_produce_distinct_settlement_ids() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
 
-        let first = c.send_tip(&recipient, &100);
-        let second = c.send_tip(&recipient, &100);
+        let first = c.send_tip(&tipper, &recipient, &token, &100);
+        let second = c.send_tip(&tipper, &recipient, &token, &100);
 
         assert_ne!(
             first, second,
@@ -491,17 +940,19 @@ mod replay_correlation {
     fn latest_nonce_reflects_all_settlements_for_replay_detection() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let recipient = Address::generate(&env);
 
         assert_eq!(c.latest_settlement_nonce(), 0, "nonce starts at 0");
 
-        c.send_tip(&recipient, &1);
+        c.send_tip(&tipper, &recipient, &token, &1);
         assert_eq!(c.latest_settlement_nonce(), 1);
 
-        c.send_tip(&recipient, &2);
+        c.send_tip(&tipper, &recipient, &token, &2);
         assert_eq!(c.latest_settlement_nonce(), 2);
 
-        c.send_tip(&recipient, &3);
+        c.send_tip(&tipper, &recipient, &token, &3);
         assert_eq!(c.latest_settlement_nonce(), 3);
 
         // Simulate replay detection: an event with settlement_id == 2 while
@@ -522,13 +973,15 @@ mod replay_correlation {
     fn global_nonce_spans_multiple_recipients_for_cross_recipient_correlation() {
         let (env, id) = setup();
         let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let tipper = tipper(&env, &c);
         let alice = Address::generate(&env);
         let bob = Address::generate(&env);
         let carol = Address::generate(&env);
 
-        let id_a = c.send_tip(&alice, &10);
-        let id_b = c.send_tip(&bob, &20);
-        let id_c = c.send_tip(&carol, &30);
+        let id_a = c.send_tip(&tipper, &alice, &token, &10);
+        let id_b = c.send_tip(&tipper, &bob, &token, &20);
+        let id_c = c.send_tip(&tipper, &carol, &token, &30);
 
         // All settlement_ids come from the same sequence regardless of recipient.
         assert_eq!(id_a, 1);
@@ -0,0 +1,434 @@
+/// Hash-locked escrow: tips to a key commitment, signed claims and refunds
+/// of tips left unclaimed.
+#[cfg(test)]
+mod escrow {
+    extern crate std;
+
+    use soroban_sdk::{
+        testutils::Address as _,
+        token::{StellarAssetClient, TokenClient},
+        Address, Bytes, BytesN, Env, String as SorobanString,
+    };
+
+    use crate::{
+        claim_message,
+        testutils::{mk_client, refund_commitment, setup, tipper},
+        AnonymousTippingClient, Error,
+    };
+    use ed25519_dalek::{Signer, SigningKey};
+
+    pub(super) fn claim_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+        BytesN::from_array(env, &key.verifying_key().to_bytes())
+    }
+
+    pub(super) fn commitment_for(env: &Env, key: &SigningKey) -> BytesN<32> {
+        env.crypto()
+            .sha256(&Bytes::from(public_key(env, key)))
+            .into()
+    }
+
+    /// `key`'s signature releasing `commitment` to `payout`.
+    fn sign_claim(
+        env: &Env,
+        c: &AnonymousTippingClient,
+        key: &SigningKey,
+        commitment: &BytesN<32>,
+        payout: &Address,
+    ) -> BytesN<64> {
+        let message = claim_message(env, &c.address, commitment, payout);
+        let mut buf = std::vec![0u8; message.len() as usize];
+        message.copy_into_slice(&mut buf);
+        BytesN::from_array(env, &key.sign(&buf).to_bytes())
+    }
+
+    /// Claim `commitment` to `payout` with a valid signature from `key`.
+    pub(super) fn claim(
+        env: &Env,
+        c: &AnonymousTippingClient,
+        key: &SigningKey,
+        commitment: &BytesN<32>,
+        payout: &Address,
+    ) {
+        let signature = sign_claim(env, c, key, commitment, payout);
+        c.claim_tips(commitment, &public_key(env, key), payout, &signature);
+    }
+
+    #[test]
+    fn commitment_tips_escrow_until_claimed_by_signature() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        c.configure_controls(&owner, &100, &60);
+        let xlm = c.get_token().unwrap();
+        let usdc = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        c.allow_token(&owner, &usdc, &1, &1_000);
+        let sender = tipper(&env, &c);
+        StellarAssetClient::new(&env, &usdc).mint(&sender, &1_000);
+
+        let key = claim_key(1);
+        let commitment = commitment_for(&env, &key);
+        assert_eq!(
+            c.send_tip_to_commitment(
+                &sender,
+                &commitment,
+                &xlm,
+                &30,
+                &refund_commitment(&env, &sender)
+            ),
+            1
+        );
+        assert_eq!(
+            c.send_tip_to_commitment(
+                &sender,
+                &commitment,
+                &xlm,
+                &12,
+                &refund_commitment(&env, &sender)
+            ),
+            2
+        );
+        assert_eq!(
+            c.send_tip_to_commitment(
+                &sender,
+                &commitment,
+                &usdc,
+                &5,
+                &refund_commitment(&env, &sender)
+            ),
+            3
+        );
+        assert_eq!(c.get_escrowed(&commitment, &xlm), 42);
+        assert_eq!(c.get_escrowed(&commitment, &usdc), 5);
+        assert_eq!(TokenClient::new(&env, &xlm).balance(&id), 42);
+
+        let fresh = Address::generate(&env);
+        claim(&env, &c, &key, &commitment, &fresh);
+        assert_eq!(TokenClient::new(&env, &xlm).balance(&fresh), 42);
+        assert_eq!(TokenClient::new(&env, &usdc).balance(&fresh), 5);
+        assert_eq!(TokenClient::new(&env, &xlm).balance(&id), 0);
+        assert_eq!(c.get_escrowed(&commitment, &xlm), 0);
+        assert_eq!(c.latest_settlement_nonce(), 3);
+    }
+
+    #[test]
+    fn claim_with_wrong_key_is_rejected() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let key = claim_key(1);
+        let commitment = commitment_for(&env, &key);
+        c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token,
+            &10,
+            &refund_commitment(&env, &sender),
+        );
+
+        let thief = Address::generate(&env);
+        let thief_key = claim_key(2);
+        let signature = sign_claim(&env, &c, &thief_key, &commitment, &thief);
+        assert_eq!(
+            c.try_claim_tips(
+                &commitment,
+                &public_key(&env, &thief_key),
+                &thief,
+                &signature
+            ),
+            Err(Ok(Error::InvalidClaimKey))
+        );
+        assert_eq!(c.get_escrowed(&commitment, &token), 10);
+
+        let unused = commitment_for(&env, &thief_key);
+        let signature = sign_claim(&env, &c, &thief_key, &unused, &thief);
+        assert_eq!(
+            c.try_claim_tips(&unused, &public_key(&env, &thief_key), &thief, &signature),
+            Err(Ok(Error::NothingToClaim))
+        );
+    }
+
+    #[test]
+    fn observed_claim_cannot_be_redirected() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let key = claim_key(1);
+        let commitment = commitment_for(&env, &key);
+        c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token,
+            &10,
+            &refund_commitment(&env, &sender),
+        );
+
+        // A front-runner replays the public key and signature from a pending
+        // claim with its own payout address.
+        let payout = Address::generate(&env);
+        let signature = sign_claim(&env, &c, &key, &commitment, &payout);
+        let thief = Address::generate(&env);
+        assert!(c
+            .try_claim_tips(&commitment, &public_key(&env, &key), &thief, &signature)
+            .is_err());
+        assert_eq!(c.get_escrowed(&commitment, &token), 10);
+
+        c.claim_tips(&commitment, &public_key(&env, &key), &payout, &signature);
+        assert_eq!(TokenClient::new(&env, &token).balance(&payout), 10);
+    }
+
+    #[test]
+    fn claimed_commitment_is_spent() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let key = claim_key(1);
+        let commitment = commitment_for(&env, &key);
+        c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token,
+            &10,
+            &refund_commitment(&env, &sender),
+        );
+        let payout = Address::generate(&env);
+        claim(&env, &c, &key, &commitment, &payout);
+
+        assert_eq!(
+            c.try_claim_tips(
+                &commitment,
+                &public_key(&env, &key),
+                &payout,
+                &sign_claim(&env, &c, &key, &commitment, &payout)
+            ),
+            Err(Ok(Error::CommitmentClaimed))
+        );
+        assert_eq!(
+            c.try_send_tip_to_commitment(
+                &sender,
+                &commitment,
+                &token,
+                &10,
+                &refund_commitment(&env, &sender)
+            ),
+            Err(Ok(Error::CommitmentClaimed))
+        );
+    }
+
+    #[test]
+    fn commitment_tips_follow_allowlist_and_pause() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        c.configure_controls(&owner, &100, &60);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let key = claim_key(1);
+        let commitment = commitment_for(&env, &key);
+
+        assert_eq!(
+            c.try_send_tip_to_commitment(
+                &sender,
+                &commitment,
+                &Address::generate(&env),
+                &1,
+                &refund_commitment(&env, &sender),
+            ),
+            Err(Ok(Error::UnsupportedToken))
+        );
+        c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token,
+            &1,
+            &refund_commitment(&env, &sender),
+        );
+
+        c.pause(&owner, &SorobanString::from_str(&env, "incident"));
+        assert_eq!(
+            c.try_send_tip_to_commitment(
+                &sender,
+                &commitment,
+                &token,
+                &1,
+                &refund_commitment(&env, &sender)
+            ),
+            Err(Ok(Error::ContractPaused))
+        );
+        assert_eq!(
+            c.try_claim_tips(
+                &commitment,
+                &public_key(&env, &key),
+                &owner,
+                &sign_claim(&env, &c, &key, &commitment, &owner)
+            ),
+            Err(Ok(Error::ContractPaused))
+        );
+    }
+}
+
+#[cfg(test)]
+mod refunds {
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token::{StellarAssetClient, TokenClient},
+        Address, BytesN,
+    };
+
+    use crate::{
+        testutils::{mk_client, refund_commitment, refund_salt, setup, tipper},
+        AnonymousTipping, Error,
+    };
+
+    use super::escrow::{claim, claim_key, commitment_for};
+
+    #[test]
+    fn unclaimed_commitment_tip_is_refunded_after_deadline() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = TokenClient::new(&env, &c.get_token().unwrap());
+        let sender = Address::generate(&env);
+        StellarAssetClient::new(&env, &token.address).mint(&sender, &100);
+        let key = claim_key(1);
+        let commitment = commitment_for(&env, &key);
+
+        let first = c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token.address,
+            &30,
+            &refund_commitment(&env, &sender),
+        );
+        c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token.address,
+            &20,
+            &refund_commitment(&env, &sender),
+        );
+        let record = c.get_escrowed_tip(&first).unwrap();
+        assert_eq!(record.refund_commitment, refund_commitment(&env, &sender));
+        assert_eq!(
+            record.claim_deadline,
+            AnonymousTipping::ESCROW_CLAIM_WINDOW_SECONDS
+        );
+
+        env.ledger()
+            .with_mut(|li| li.timestamp = record.claim_deadline - 1);
+        assert_eq!(
+            c.try_refund_tip(&first, &sender, &refund_salt(&env)),
+            Err(Ok(Error::ClaimWindowOpen))
+        );
+
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        assert_eq!(c.refund_tip(&first, &sender, &refund_salt(&env)), 30);
+        assert_eq!(token.balance(&sender), 80);
+        assert_eq!(c.get_escrowed(&commitment, &token.address), 20);
+        assert_eq!(c.get_escrowed_tip(&first), None);
+        assert_eq!(
+            c.try_refund_tip(&first, &sender, &refund_salt(&env)),
+            Err(Ok(Error::NothingToRefund))
+        );
+
+        // The rest stays claimable by the recipient.
+        let fresh = Address::generate(&env);
+        claim(&env, &c, &key, &commitment, &fresh);
+        assert_eq!(token.balance(&fresh), 20);
+    }
+
+    #[test]
+    fn claimed_commitment_tip_cannot_be_refunded() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let key = claim_key(1);
+        let commitment = commitment_for(&env, &key);
+        let settlement_id = c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token,
+            &10,
+            &refund_commitment(&env, &sender),
+        );
+        claim(&env, &c, &key, &commitment, &Address::generate(&env));
+
+        env.ledger()
+            .with_mut(|li| li.timestamp += AnonymousTipping::ESCROW_CLAIM_WINDOW_SECONDS);
+        assert_eq!(
+            c.try_refund_tip(&settlement_id, &sender, &refund_salt(&env)),
+            Err(Ok(Error::TipAlreadyClaimed))
+        );
+        // Direct tips are never escrowed for refund.
+        let direct = c.send_tip(&sender, &Address::generate(&env), &token, &10);
+        assert_eq!(
+            c.try_refund_tip(&direct, &sender, &refund_salt(&env)),
+            Err(Ok(Error::NothingToRefund))
+        );
+    }
+
+    #[test]
+    fn refund_requires_original_sender_auth() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let commitment = commitment_for(&env, &claim_key(1));
+        let settlement_id = c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token,
+            &10,
+            &refund_commitment(&env, &sender),
+        );
+        env.ledger()
+            .with_mut(|li| li.timestamp += AnonymousTipping::ESCROW_CLAIM_WINDOW_SECONDS);
+
+        env.set_auths(&[]);
+        assert!(c
+            .try_refund_tip(&settlement_id, &sender, &refund_salt(&env))
+            .is_err());
+        assert_eq!(c.get_escrowed(&commitment, &token), 10);
+    }
+
+    #[test]
+    fn refund_requires_the_committed_sender_and_salt() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let commitment = commitment_for(&env, &claim_key(1));
+        let settlement_id = c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token,
+            &10,
+            &refund_commitment(&env, &sender),
+        );
+        env.ledger()
+            .with_mut(|li| li.timestamp += AnonymousTipping::ESCROW_CLAIM_WINDOW_SECONDS);
+
+        let other = Address::generate(&env);
+        assert_eq!(
+            c.try_refund_tip(&settlement_id, &other, &refund_salt(&env)),
+            Err(Ok(Error::Unauthorized))
+        );
+        let wrong_salt = BytesN::from_array(&env, &[1; 32]);
+        assert_eq!(
+            c.try_refund_tip(&settlement_id, &sender, &wrong_salt),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            c.refund_tip(&settlement_id, &sender, &refund_salt(&env)),
+            10
+        );
+    }
+}
@@ -0,0 +1,178 @@
+/// Platform fee: splitting, notice period and subscriptions' locked rate.
+#[cfg(test)]
+mod fees {
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token::{StellarAssetClient, TokenClient},
+        Address, BytesN, Env,
+    };
+
+    use crate::{
+        testutils::{mk_client, refund_commitment, setup, tipper},
+        AnonymousTipping, AnonymousTippingClient, Error,
+    };
+
+    /// Announce a fee and let its notice run out.
+    fn apply_fee(
+        env: &Env,
+        c: &AnonymousTippingClient,
+        owner: &Address,
+        fee_bps: u32,
+        treasury: &Address,
+    ) {
+        c.set_fee(owner, &fee_bps, &Some(treasury.clone()));
+        env.ledger()
+            .with_mut(|li| li.timestamp += AnonymousTipping::FEE_NOTICE_SECONDS);
+    }
+
+    #[test]
+    fn fee_is_split_to_treasury_and_net_credited() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = TokenClient::new(&env, &c.get_token().unwrap());
+        let owner = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let sender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let commitment = BytesN::from_array(&env, &[7; 32]);
+        StellarAssetClient::new(&env, &token.address).mint(&sender, &2_000);
+
+        c.configure_controls(&owner, &10, &60);
+        apply_fee(&env, &c, &owner, 250, &treasury);
+        assert_eq!(c.get_fee_config().fee_bps, 250);
+
+        c.send_tip(&sender, &recipient, &token.address, &1_000);
+        assert_eq!(token.balance(&treasury), 25);
+        assert_eq!(c.get_tips(&recipient, &token.address), 975);
+        assert_eq!(c.get_claimable(&recipient, &token.address), 975);
+
+        // The fee rounds down, and commitment tips pay it too.
+        c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token.address,
+            &39,
+            &refund_commitment(&env, &sender),
+        );
+        assert_eq!(token.balance(&treasury), 25);
+        c.send_tip_to_commitment(
+            &sender,
+            &commitment,
+            &token.address,
+            &40,
+            &refund_commitment(&env, &sender),
+        );
+        assert_eq!(token.balance(&treasury), 26);
+        assert_eq!(c.get_escrowed(&commitment, &token.address), 78);
+        assert_eq!(token.balance(&id), 975 + 78);
+
+        // Subscription collections pay it from the pulled amount.
+        token.approve(&sender, &id, &100, &(env.ledger().sequence() + 1_000));
+        let sub_id = c.subscribe(&sender, &recipient, &token.address, &100, &60, &1);
+        c.collect(&sub_id);
+        assert_eq!(token.balance(&treasury), 28);
+        assert_eq!(c.get_tips(&recipient, &token.address), 975 + 98);
+    }
+
+    #[test]
+    fn fee_is_capped_and_needs_treasury() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        c.configure_controls(&owner, &10, &60);
+
+        assert_eq!(
+            c.try_set_fee(
+                &owner,
+                &(AnonymousTipping::MAX_FEE_BPS + 1),
+                &Some(treasury)
+            ),
+            Err(Ok(Error::InvalidFeeConfig))
+        );
+        assert_eq!(
+            c.try_set_fee(&owner, &100, &None),
+            Err(Ok(Error::InvalidFeeConfig))
+        );
+        // A zero fee needs no treasury.
+        c.set_fee(&owner, &0, &None);
+        assert_eq!(c.get_fee_config().fee_bps, 0);
+    }
+
+    #[test]
+    fn fee_change_applies_only_after_notice() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let owner = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        c.configure_controls(&owner, &10, &60);
+        let notice = AnonymousTipping::FEE_NOTICE_SECONDS;
+
+        // Even the first fee waits: tips sent during the notice pay nothing.
+        c.set_fee(&owner, &100, &Some(treasury.clone()));
+        assert_eq!(c.get_fee_config().fee_bps, 0);
+        assert_eq!(c.get_pending_fee().unwrap().effective_at, 1_000 + notice);
+        c.send_tip(&sender, &recipient, &token, &1_000);
+        assert_eq!(c.get_tips(&recipient, &token), 1_000);
+
+        env.ledger()
+            .with_mut(|li| li.timestamp = 1_000 + notice - 1);
+        assert_eq!(c.get_fee_config().fee_bps, 0);
+        env.ledger().with_mut(|li| li.timestamp = 1_000 + notice);
+        assert_eq!(c.get_fee_config().fee_bps, 100);
+        assert_eq!(c.get_fee_config().updated_at, 1_000 + notice);
+        assert_eq!(c.get_pending_fee(), None);
+
+        // A replacement restarts the notice and the old fee stays meanwhile.
+        c.set_fee(&owner, &1_000, &Some(treasury.clone()));
+        env.ledger().with_mut(|li| li.timestamp += notice - 1);
+        c.set_fee(&owner, &50, &Some(treasury.clone()));
+        env.ledger().with_mut(|li| li.timestamp += notice - 1);
+        assert_eq!(c.get_fee_config().fee_bps, 100);
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        assert_eq!(c.get_fee_config().fee_bps, 50);
+    }
+
+    #[test]
+    fn subscription_keeps_the_fee_it_started_with() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = TokenClient::new(&env, &c.get_token().unwrap());
+        let owner = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        c.configure_controls(&owner, &10, &60);
+        apply_fee(&env, &c, &owner, 100, &treasury);
+
+        let early = c.subscribe(&sender, &recipient, &token.address, &1_000, &60, &1);
+        assert_eq!(c.get_subscription(&early).unwrap().fee_bps, 100);
+        apply_fee(&env, &c, &owner, 1_000, &treasury);
+        let late = c.subscribe(&sender, &recipient, &token.address, &1_000, &60, &1);
+
+        token.approve(&sender, &id, &2_000, &(env.ledger().sequence() + 1_000));
+        c.collect(&early);
+        assert_eq!(token.balance(&treasury), 10);
+        c.collect(&late);
+        assert_eq!(token.balance(&treasury), 110);
+    }
+
+    #[test]
+    fn fee_is_owner_only() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        c.configure_controls(&owner, &10, &60);
+
+        assert_eq!(
+            c.try_set_fee(&outsider, &100, &Some(outsider.clone())),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(c.get_fee_config().fee_bps, 0);
+    }
+}
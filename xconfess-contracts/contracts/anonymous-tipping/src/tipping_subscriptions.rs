@@ -0,0 +1,167 @@
+/// Recurring tips pulled through a token allowance.
+#[cfg(test)]
+mod subscriptions {
+    extern crate std;
+
+    use soroban_sdk::{
+        testutils::{Address as _, Events as _, Ledger},
+        token::{StellarAssetClient, TokenClient},
+        Address, Env, Symbol, TryFromVal,
+    };
+    use std::vec::Vec;
+
+    use crate::{
+        testutils::{mk_client, setup, tipper},
+        AnonymousTippingClient, Error,
+    };
+
+    /// Let the tipping contract pull up to `amount` from `sender`.
+    fn approve(env: &Env, c: &AnonymousTippingClient, sender: &Address, amount: i128) {
+        TokenClient::new(env, &c.get_token().unwrap()).approve(
+            sender,
+            &c.address,
+            &amount,
+            &(env.ledger().sequence() + 1_000),
+        );
+    }
+
+    #[test]
+    fn collect_pulls_every_due_period() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = TokenClient::new(&env, &c.get_token().unwrap());
+        let sender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        StellarAssetClient::new(&env, &token.address).mint(&sender, &100);
+        approve(&env, &c, &sender, 100);
+
+        let sub_id = c.subscribe(&sender, &recipient, &token.address, &10, &100, &3);
+        c.collect(&sub_id);
+        assert_eq!(c.get_tips(&recipient, &token.address), 10);
+        assert_eq!(c.try_collect(&sub_id), Err(Ok(Error::NothingDue)));
+
+        // Two more periods fall due; the schedule never exceeds max_periods.
+        env.ledger().with_mut(|li| li.timestamp += 1_000);
+        // One settlement covers both periods.
+        assert_eq!(c.collect(&sub_id), 2);
+        assert_eq!(c.latest_settlement_nonce(), 2);
+        assert_eq!(c.get_claimable(&recipient, &token.address), 30);
+        assert_eq!(token.balance(&sender), 70);
+        // The last period is paid, so the subscription is gone.
+        assert_eq!(c.get_subscription(&sub_id), None);
+        assert_eq!(c.try_collect(&sub_id), Err(Ok(Error::SubscriptionNotFound)));
+    }
+
+    #[test]
+    fn collect_publishes_a_settlement_and_a_subscription_settlement() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        approve(&env, &c, &sender, 100);
+        let sub_id = c.subscribe(&sender, &Address::generate(&env), &token, &10, &60, &5);
+
+        c.collect(&sub_id);
+        let topics: Vec<Symbol> = env
+            .events()
+            .all()
+            .iter()
+            .filter(|(contract, _, _)| *contract == id)
+            .map(|(_, topics, _)| Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap())
+            .collect();
+        assert_eq!(
+            topics,
+            [
+                Symbol::new(&env, "tip_settl"),
+                Symbol::new(&env, "tip_sub_settl")
+            ]
+        );
+    }
+
+    #[test]
+    fn removing_the_token_does_not_strand_a_subscription() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let owner = Address::generate(&env);
+        c.configure_controls(&owner, &10, &60);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        approve(&env, &c, &sender, 100);
+        let sub_id = c.subscribe(&sender, &recipient, &token, &10, &60, &2);
+
+        c.remove_token(&owner, &token);
+        c.collect(&sub_id);
+        env.ledger().with_mut(|li| li.timestamp += 60);
+        c.collect(&sub_id);
+        assert_eq!(c.get_tips(&recipient, &token), 20);
+        assert_eq!(c.get_subscription(&sub_id), None);
+    }
+
+    #[test]
+    fn collect_requires_allowance_for_due_periods() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        approve(&env, &c, &sender, 15);
+
+        let sub_id = c.subscribe(&sender, &recipient, &token, &10, &60, &12);
+        c.collect(&sub_id);
+        env.ledger().with_mut(|li| li.timestamp += 60);
+        assert_eq!(c.try_collect(&sub_id), Err(Ok(Error::AllowanceTooLow)));
+        assert_eq!(c.get_subscription(&sub_id).unwrap().periods_collected, 1);
+        assert_eq!(c.get_tips(&recipient, &token), 10);
+
+        approve(&env, &c, &sender, 100);
+        c.collect(&sub_id);
+        assert_eq!(c.get_tips(&recipient, &token), 20);
+    }
+
+    #[test]
+    fn cancelled_subscription_stops_collecting() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        approve(&env, &c, &sender, 100);
+        let sub_id = c.subscribe(&sender, &recipient, &token, &10, &60, &5);
+
+        env.set_auths(&[]);
+        assert!(c.try_cancel_subscription(&sub_id).is_err());
+        env.mock_all_auths();
+
+        c.cancel_subscription(&sub_id);
+        assert_eq!(c.get_subscription(&sub_id), None);
+        assert_eq!(c.try_collect(&sub_id), Err(Ok(Error::SubscriptionNotFound)));
+        assert_eq!(c.get_tips(&recipient, &token), 0);
+    }
+
+    #[test]
+    fn subscription_terms_are_validated() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+
+        assert_eq!(
+            c.try_subscribe(&sender, &recipient, &token, &10, &0, &5),
+            Err(Ok(Error::InvalidSubscription))
+        );
+        assert_eq!(
+            c.try_subscribe(&sender, &recipient, &token, &10, &60, &0),
+            Err(Ok(Error::InvalidSubscription))
+        );
+        assert_eq!(
+            c.try_subscribe(&sender, &recipient, &token, &0, &60, &5),
+            Err(Ok(Error::InvalidTipAmount))
+        );
+        assert_eq!(
+            c.try_subscribe(&sender, &recipient, &Address::generate(&env), &10, &60, &5),
+            Err(Ok(Error::UnsupportedToken))
+        );
+    }
+}
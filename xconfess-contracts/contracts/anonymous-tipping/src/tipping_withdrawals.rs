@@ -0,0 +1,88 @@
+/// Tips held by the contract until the recipient withdraws them.
+#[cfg(test)]
+mod withdrawals {
+    use soroban_sdk::{
+        testutils::Address as _,
+        token::{StellarAssetClient, TokenClient},
+        Address, String as SorobanString,
+    };
+
+    use crate::{
+        testutils::{mk_client, setup, tipper},
+        Error,
+    };
+
+    #[test]
+    fn tip_escrows_tokens_until_recipient_withdraws() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = TokenClient::new(&env, &c.get_token().unwrap());
+        let sender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        StellarAssetClient::new(&env, &token.address).mint(&sender, &100);
+
+        c.send_tip(&sender, &recipient, &token.address, &30i128);
+        c.send_tip_with_proof(
+            &sender,
+            &recipient,
+            &token.address,
+            &20i128,
+            &Some(SorobanString::from_str(&env, "receipt1")),
+        );
+
+        assert_eq!(token.balance(&sender), 50);
+        assert_eq!(token.balance(&recipient), 0);
+        assert_eq!(token.balance(&id), 50);
+        assert_eq!(c.get_claimable(&recipient, &token.address), 50);
+
+        c.withdraw(&recipient, &token.address, &15);
+        assert_eq!(token.balance(&recipient), 15);
+        assert_eq!(c.get_claimable(&recipient, &token.address), 35);
+
+        c.withdraw(&recipient, &token.address, &35);
+        assert_eq!(token.balance(&recipient), 50);
+        assert_eq!(token.balance(&id), 0);
+        assert_eq!(c.get_claimable(&recipient, &token.address), 0);
+        // The lifetime total is unaffected by withdrawals.
+        assert_eq!(c.get_tips(&recipient, &token.address), 50);
+    }
+
+    #[test]
+    fn withdraw_is_bounded_by_claimable_balance() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        let other = Address::generate(&env);
+        c.send_tip(&sender, &recipient, &token, &10i128);
+
+        assert_eq!(
+            c.try_withdraw(&recipient, &token, &11),
+            Err(Ok(Error::InsufficientClaimable))
+        );
+        assert_eq!(
+            c.try_withdraw(&other, &token, &1),
+            Err(Ok(Error::InsufficientClaimable))
+        );
+        assert_eq!(
+            c.try_withdraw(&recipient, &token, &0),
+            Err(Ok(Error::InvalidTipAmount))
+        );
+        assert_eq!(c.get_claimable(&recipient, &token), 10);
+    }
+
+    #[test]
+    fn withdraw_requires_recipient_auth() {
+        let (env, id) = setup();
+        let c = mk_client(&env, &id);
+        let token = c.get_token().unwrap();
+        let sender = tipper(&env, &c);
+        let recipient = Address::generate(&env);
+        c.send_tip(&sender, &recipient, &token, &10i128);
+
+        env.set_auths(&[]);
+        assert!(c.try_withdraw(&recipient, &token, &10).is_err());
+        assert_eq!(c.get_claimable(&recipient, &token), 10);
+    }
+}
@@ -1,5 +1,5 @@
 use anonymous_tipping::{AnonymousTipping, AnonymousTippingClient};
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Address, Env};
 
 #[test]
 fn benchmark_send_tip() {
@@ -7,13 +7,19 @@ fn benchmark_send_tip() {
     let contract_id = env.register(AnonymousTipping, ());
     let client = AnonymousTippingClient::new(&env, &contract_id);
 
-    client.init();
+    env.mock_all_auths();
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.init(&token);
+    let sender = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&sender, &1_000_i128);
 
     let recipient = Address::generate(&env);
     let amount = 100_i128;
 
     env.cost_estimate().budget().reset_default();
-    let _ = client.send_tip(&recipient, &amount);
+    let _ = client.send_tip(&sender, &recipient, &token, &amount);
 
     let cpu = env.cost_estimate().budget().cpu_instruction_cost();
     let mem = env.cost_estimate().budget().memory_bytes_cost();
@@ -28,14 +34,20 @@ fn benchmark_get_tips() {
     let contract_id = env.register(AnonymousTipping, ());
     let client = AnonymousTippingClient::new(&env, &contract_id);
 
-    client.init();
+    env.mock_all_auths();
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.init(&token);
+    let sender = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&sender, &1_000_i128);
 
     let recipient = Address::generate(&env);
     let amount = 100_i128;
-    let _ = client.send_tip(&recipient, &amount);
+    let _ = client.send_tip(&sender, &recipient, &token, &amount);
 
     env.cost_estimate().budget().reset_default();
-    let _ = client.get_tips(&recipient);
+    let _ = client.get_tips(&recipient, &token);
 
     let cpu = env.cost_estimate().budget().cpu_instruction_cost();
     let mem = env.cost_estimate().budget().memory_bytes_cost();
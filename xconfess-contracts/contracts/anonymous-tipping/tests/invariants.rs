@@ -9,18 +9,27 @@
 //! | I3 | Nonce coherence: `latest_settlement_nonce()` == total successful settlements | `nonce_equals_total_successful_settlements` |
 //! | I4 | Balance non-regression: recipient totals can never decrease | `recipient_total_never_decreases` |
 //! | I5 | Precision: i128 accumulation is exact — no rounding | `tip_accumulation_exact_no_rounding` |
-//! | I6 | Rate limit bound: per-wallet count never exceeds configured limit | `rate_limit_blocks_at_exact_window_cap` |
+//! | I6 | Rate limit bound: per-sender count never exceeds configured limit | `rate_limit_blocks_at_exact_window_cap` |
 //! | I7 | Pause completeness: all state-changing ops fail while paused | `pause_blocks_all_mutations` |
 //! | I8 | Zero/negative amounts never mutate state | `invalid_amounts_never_mutate_state` |
 //! | I9 | Overflow safety: TotalOverflow returned before silent corruption | `overflow_returns_error_not_silent_corruption` |
 //! | I10 | Recipient isolation: tipping one address never alters another's total | `recipients_are_mutually_isolated` |
 //! | I11 | send_tip and send_tip_with_proof(None) produce identical outcomes | `send_tip_and_proof_none_identical_invariant` |
 //! | I12 | GlobalTipCount coherence post-migration | `global_tip_count_coherent_with_nonce` |
+//! | I13 | Escrow solvency: contract token balance == sum of claimable balances | `escrow_balance_equals_sum_of_claimable` |
+//! | I14 | Refunds keep escrow solvent and GlobalTipCount equal to standing tips | `refunds_keep_escrow_and_tip_count_consistent`, `refunding_a_pre_migration_tip_keeps_tip_count` |
 
 extern crate std;
 
-use anonymous_tipping::{AnonymousTipping, AnonymousTippingClient, Error};
-use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString};
+use anonymous_tipping::{
+    testutils::{refund_commitment, refund_salt, tipper},
+    AnonymousTipping, AnonymousTippingClient, Error,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::TokenClient,
+    Address, BytesN, Env, String as SorobanString, Vec,
+};
 
 // ── helpers ───────────────────────────────────────────────────────────────────
 
@@ -29,7 +38,11 @@ fn setup() -> (Env, AnonymousTippingClient<'static>) {
     env.mock_all_auths();
     let id = env.register(AnonymousTipping, ());
     let client = AnonymousTippingClient::new(&env, &id);
-    client.init();
+    let token_admin = Address::generate(&env);
+    client.init(
+        &env.register_stellar_asset_contract_v2(token_admin)
+            .address(),
+    );
     (env, client)
 }
 
@@ -47,17 +60,19 @@ fn owner_setup() -> (Env, Address, AnonymousTippingClient<'static>) {
 #[test]
 fn recipient_total_equals_sum_of_tips() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let alice = Address::generate(&env);
 
     let amounts: &[i128] = &[1, 10, 100, 999, 1, 42, 7];
     let expected: i128 = amounts.iter().sum();
 
     for &a in amounts {
-        client.send_tip(&alice, &a);
+        client.send_tip(&tipper, &alice, &token, &a);
     }
 
     assert_eq!(
-        client.get_tips(&alice),
+        client.get_tips(&alice, &token),
         expected,
         "I1: recipient total must equal exact arithmetic sum of all tips"
     );
@@ -67,6 +82,8 @@ fn recipient_total_equals_sum_of_tips() {
 #[test]
 fn balance_conservation_holds_across_multiple_recipients() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let alice = Address::generate(&env);
     let bob = Address::generate(&env);
     let carol = Address::generate(&env);
@@ -76,16 +93,16 @@ fn balance_conservation_holds_across_multiple_recipients() {
     let carol_tips: &[i128] = &[42];
 
     // Interleave deliberately to ensure no cross-contamination
-    client.send_tip(&alice, &alice_tips[0]);
-    client.send_tip(&bob, &bob_tips[0]);
-    client.send_tip(&carol, &carol_tips[0]);
-    client.send_tip(&alice, &alice_tips[1]);
-    client.send_tip(&bob, &bob_tips[1]);
-    client.send_tip(&alice, &alice_tips[2]);
-
-    assert_eq!(client.get_tips(&alice), 350i128, "I1: alice total");
-    assert_eq!(client.get_tips(&bob), 1000i128, "I1: bob total");
-    assert_eq!(client.get_tips(&carol), 42i128, "I1: carol total");
+    client.send_tip(&tipper, &alice, &token, &alice_tips[0]);
+    client.send_tip(&tipper, &bob, &token, &bob_tips[0]);
+    client.send_tip(&tipper, &carol, &token, &carol_tips[0]);
+    client.send_tip(&tipper, &alice, &token, &alice_tips[1]);
+    client.send_tip(&tipper, &bob, &token, &bob_tips[1]);
+    client.send_tip(&tipper, &alice, &token, &alice_tips[2]);
+
+    assert_eq!(client.get_tips(&alice, &token), 350i128, "I1: alice total");
+    assert_eq!(client.get_tips(&bob, &token), 1000i128, "I1: bob total");
+    assert_eq!(client.get_tips(&carol, &token), 42i128, "I1: carol total");
 }
 
 // ── I2: Nonce monotonicity ────────────────────────────────────────────────────
@@ -94,11 +111,13 @@ fn balance_conservation_holds_across_multiple_recipients() {
 #[test]
 fn settlement_nonce_strictly_monotonic() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     let mut prev = 0u64;
     for _ in 0..10 {
-        let id = client.send_tip(&recipient, &1i128);
+        let id = client.send_tip(&tipper, &recipient, &token, &1i128);
         assert_eq!(id, prev + 1, "I2: each settlement_id must be prev + 1");
         prev = id;
     }
@@ -108,12 +127,14 @@ fn settlement_nonce_strictly_monotonic() {
 #[test]
 fn nonce_monotonic_across_different_recipients() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
 
-    let id1 = client.send_tip(&r1, &1i128);
-    let id2 = client.send_tip(&r2, &1i128);
-    let id3 = client.send_tip(&r1, &1i128);
+    let id1 = client.send_tip(&tipper, &r1, &token, &1i128);
+    let id2 = client.send_tip(&tipper, &r2, &token, &1i128);
+    let id3 = client.send_tip(&tipper, &r1, &token, &1i128);
 
     assert!(id2 > id1, "I2: id2 must exceed id1");
     assert!(id3 > id2, "I2: id3 must exceed id2");
@@ -126,12 +147,14 @@ fn nonce_monotonic_across_different_recipients() {
 #[test]
 fn nonce_equals_total_successful_settlements() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     assert_eq!(client.latest_settlement_nonce(), 0, "I3: nonce starts at 0");
 
     for n in 1u64..=20 {
-        client.send_tip(&recipient, &(n as i128));
+        client.send_tip(&tipper, &recipient, &token, &(n as i128));
         assert_eq!(
             client.latest_settlement_nonce(),
             n,
@@ -144,14 +167,16 @@ fn nonce_equals_total_successful_settlements() {
 #[test]
 fn failed_tips_do_not_advance_nonce() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
-    client.send_tip(&recipient, &5i128);
+    client.send_tip(&tipper, &recipient, &token, &5i128);
     let nonce_before = client.latest_settlement_nonce();
 
     // Invalid amount — must fail without touching nonce
-    let _ = client.try_send_tip(&recipient, &0i128);
-    let _ = client.try_send_tip(&recipient, &(-1i128));
+    let _ = client.try_send_tip(&tipper, &recipient, &token, &0i128);
+    let _ = client.try_send_tip(&tipper, &recipient, &token, &(-1i128));
 
     assert_eq!(
         client.latest_settlement_nonce(),
@@ -167,13 +192,15 @@ fn failed_tips_do_not_advance_nonce() {
 #[test]
 fn recipient_total_never_decreases() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     let mut prev_total: i128 = 0;
     let amounts: &[i128] = &[100, 1, 999, 50, 200];
     for &a in amounts {
-        client.send_tip(&recipient, &a);
-        let new_total = client.get_tips(&recipient);
+        client.send_tip(&tipper, &recipient, &token, &a);
+        let new_total = client.get_tips(&recipient, &token);
         assert!(
             new_total >= prev_total,
             "I4: recipient total must never decrease (was {prev_total}, now {new_total})"
@@ -189,16 +216,18 @@ fn recipient_total_never_decreases() {
 #[test]
 fn tip_accumulation_exact_no_rounding() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     let unit: i128 = 7; // prime to surface any rounding
     let n: u32 = 100;
     for _ in 0..n {
-        client.send_tip(&recipient, &unit);
+        client.send_tip(&tipper, &recipient, &token, &unit);
     }
 
     assert_eq!(
-        client.get_tips(&recipient),
+        client.get_tips(&recipient, &token),
         unit * n as i128,
         "I5: i128 accumulation must be exact — no rounding"
     );
@@ -208,16 +237,18 @@ fn tip_accumulation_exact_no_rounding() {
 #[test]
 fn odd_amounts_accumulate_without_rounding() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     let amounts: &[i128] = &[3, 7, 11, 13, 17, 19, 23];
     let expected: i128 = amounts.iter().sum();
     for &a in amounts {
-        client.send_tip(&recipient, &a);
+        client.send_tip(&tipper, &recipient, &token, &a);
     }
 
     assert_eq!(
-        client.get_tips(&recipient),
+        client.get_tips(&recipient, &token),
         expected,
         "I5: prime amounts must sum exactly"
     );
@@ -227,11 +258,17 @@ fn odd_amounts_accumulate_without_rounding() {
 #[test]
 fn unit_tip_precision() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     for i in 1i128..=50 {
-        client.send_tip(&recipient, &1i128);
-        assert_eq!(client.get_tips(&recipient), i, "I5: unit tip count == {i}");
+        client.send_tip(&tipper, &recipient, &token, &1i128);
+        assert_eq!(
+            client.get_tips(&recipient, &token),
+            i,
+            "I5: unit tip count == {i}"
+        );
     }
 }
 
@@ -242,45 +279,55 @@ fn unit_tip_precision() {
 #[test]
 fn rate_limit_blocks_at_exact_window_cap() {
     let (env, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let cap: u32 = 5;
     client.configure_controls(&owner, &cap, &60u64);
 
     let wallet = Address::generate(&env);
     for i in 0..cap {
-        let result = client.try_send_tip(&wallet, &1i128);
+        let result = client.try_send_tip(&tipper, &wallet, &token, &1i128);
         assert!(result.is_ok(), "I6: tip {} (of {cap}) must succeed", i + 1);
     }
 
     // The very next tip must be rate-limited
     assert_eq!(
-        client.try_send_tip(&wallet, &1i128),
+        client.try_send_tip(&tipper, &wallet, &token, &1i128),
         Err(Ok(Error::RateLimited)),
         "I6: tip {} must be blocked by rate limit",
         cap + 1
     );
 }
 
-/// Rate limiting is per-wallet: other wallets are unaffected by one wallet
-/// exhausting its window.
+/// Rate limiting is per sender wallet: other senders, including those
+/// tipping the same recipient, are unaffected by one sender exhausting its
+/// window.
 #[test]
 fn rate_limit_is_per_wallet_not_global() {
     let (env, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper_a = tipper(&env, &client);
+    let tipper_b = tipper(&env, &client);
     client.configure_controls(&owner, &1u32, &60u64);
 
-    let wallet_a = Address::generate(&env);
-    let wallet_b = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let other = Address::generate(&env);
 
-    // wallet_a exhausts its window
-    assert!(client.try_send_tip(&wallet_a, &1i128).is_ok());
+    // tipper_a exhausts its window, whichever recipient it tips next
+    assert!(client
+        .try_send_tip(&tipper_a, &recipient, &token, &1i128)
+        .is_ok());
     assert_eq!(
-        client.try_send_tip(&wallet_a, &1i128),
+        client.try_send_tip(&tipper_a, &other, &token, &1i128),
         Err(Ok(Error::RateLimited))
     );
 
-    // wallet_b is unaffected
+    // tipper_b can still tip the same recipient
     assert!(
-        client.try_send_tip(&wallet_b, &1i128).is_ok(),
-        "I6: wallet_b must not be affected by wallet_a's rate exhaustion"
+        client
+            .try_send_tip(&tipper_b, &recipient, &token, &1i128)
+            .is_ok(),
+        "I6: tipper_b must not be affected by tipper_a's rate exhaustion"
     );
 }
 
@@ -291,11 +338,13 @@ fn rate_limit_is_per_wallet_not_global() {
 #[test]
 fn pause_blocks_all_mutations() {
     let (env, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     // Establish baseline state
-    client.send_tip(&recipient, &10i128);
-    let baseline_total = client.get_tips(&recipient);
+    client.send_tip(&tipper, &recipient, &token, &10i128);
+    let baseline_total = client.get_tips(&recipient, &token);
     let baseline_nonce = client.latest_settlement_nonce();
 
     client.pause(&owner, &SorobanString::from_str(&env, "maintenance"));
@@ -303,19 +352,19 @@ fn pause_blocks_all_mutations() {
 
     // Both tip variants must be blocked
     assert_eq!(
-        client.try_send_tip(&recipient, &1i128),
+        client.try_send_tip(&tipper, &recipient, &token, &1i128),
         Err(Ok(Error::ContractPaused)),
         "I7: send_tip must fail while paused"
     );
     assert_eq!(
-        client.try_send_tip_with_proof(&recipient, &1i128, &None),
+        client.try_send_tip_with_proof(&tipper, &recipient, &token, &1i128, &None),
         Err(Ok(Error::ContractPaused)),
         "I7: send_tip_with_proof must fail while paused"
     );
 
     // State must be completely unchanged
     assert_eq!(
-        client.get_tips(&recipient),
+        client.get_tips(&recipient, &token),
         baseline_total,
         "I7: recipient total must not change while paused"
     );
@@ -326,7 +375,7 @@ fn pause_blocks_all_mutations() {
     );
 
     // Read operations must remain available
-    let _ = client.get_tips(&recipient);
+    let _ = client.get_tips(&recipient, &token);
     let _ = client.latest_settlement_nonce();
     let _ = client.is_paused();
     let _ = client.get_rate_limit_config();
@@ -336,17 +385,19 @@ fn pause_blocks_all_mutations() {
 #[test]
 fn unpause_restores_settlement_invariant() {
     let (env, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     client.pause(&owner, &SorobanString::from_str(&env, "incident"));
     client.unpause(&owner, &SorobanString::from_str(&env, "resolved"));
 
-    let id = client.send_tip(&recipient, &42i128);
+    let id = client.send_tip(&tipper, &recipient, &token, &42i128);
     assert_eq!(
         id, 1,
         "I7: first tip after unpause must get settlement_id 1"
     );
-    assert_eq!(client.get_tips(&recipient), 42i128);
+    assert_eq!(client.get_tips(&recipient, &token), 42i128);
 }
 
 // ── I8: Zero/negative amounts never mutate state ─────────────────────────────
@@ -354,15 +405,17 @@ fn unpause_restores_settlement_invariant() {
 #[test]
 fn invalid_amounts_never_mutate_state() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     let initial_nonce = client.latest_settlement_nonce();
-    let initial_total = client.get_tips(&recipient);
+    let initial_total = client.get_tips(&recipient, &token);
 
     let invalid_amounts: &[i128] = &[0, -1, -100, i128::MIN, i128::MIN + 1];
     for &a in invalid_amounts {
-        let _ = client.try_send_tip(&recipient, &a);
-        let _ = client.try_send_tip_with_proof(&recipient, &a, &None);
+        let _ = client.try_send_tip(&tipper, &recipient, &token, &a);
+        let _ = client.try_send_tip_with_proof(&tipper, &recipient, &token, &a, &None);
     }
 
     assert_eq!(
@@ -371,7 +424,7 @@ fn invalid_amounts_never_mutate_state() {
         "I8: nonce must not advance for any invalid amount"
     );
     assert_eq!(
-        client.get_tips(&recipient),
+        client.get_tips(&recipient, &token),
         initial_total,
         "I8: recipient total must not change for any invalid amount"
     );
@@ -384,14 +437,16 @@ fn invalid_amounts_never_mutate_state() {
 #[test]
 fn overflow_returns_error_not_silent_corruption() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     // Load total to near max
-    client.send_tip(&recipient, &(i128::MAX - 50));
-    let pre_overflow_total = client.get_tips(&recipient);
+    client.send_tip(&tipper, &recipient, &token, &(i128::MAX - 50));
+    let pre_overflow_total = client.get_tips(&recipient, &token);
 
     // This would overflow — must be rejected
-    let result = client.try_send_tip(&recipient, &100i128);
+    let result = client.try_send_tip(&tipper, &recipient, &token, &100i128);
     assert_eq!(
         result,
         Err(Ok(Error::TotalOverflow)),
@@ -400,7 +455,7 @@ fn overflow_returns_error_not_silent_corruption() {
 
     // Total must be unchanged
     assert_eq!(
-        client.get_tips(&recipient),
+        client.get_tips(&recipient, &token),
         pre_overflow_total,
         "I9: total must not be corrupted after overflow rejection"
     );
@@ -410,11 +465,13 @@ fn overflow_returns_error_not_silent_corruption() {
 #[test]
 fn overflow_by_one_is_caught() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
-    client.send_tip(&recipient, &i128::MAX);
+    client.send_tip(&tipper, &recipient, &token, &i128::MAX);
 
-    let result = client.try_send_tip(&recipient, &1i128);
+    let result = client.try_send_tip(&tipper, &recipient, &token, &1i128);
     assert_eq!(
         result,
         Err(Ok(Error::TotalOverflow)),
@@ -428,17 +485,19 @@ fn overflow_by_one_is_caught() {
 #[test]
 fn recipients_are_mutually_isolated() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipients: std::vec::Vec<Address> = (0..5).map(|_| Address::generate(&env)).collect();
 
     // Tip only the first recipient
     for _ in 0..10 {
-        client.send_tip(&recipients[0], &1i128);
+        client.send_tip(&tipper, &recipients[0], &token, &1i128);
     }
 
     // All other recipients must remain at 0
     for r in &recipients[1..] {
         assert_eq!(
-            client.get_tips(r),
+            client.get_tips(r, &token),
             0i128,
             "I10: untouched recipients must have 0 total"
         );
@@ -450,6 +509,8 @@ fn recipients_are_mutually_isolated() {
 #[test]
 fn round_robin_tipping_preserves_per_recipient_isolation() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let n = 4usize;
     let amount = 100i128;
     let rounds = 5usize;
@@ -457,13 +518,13 @@ fn round_robin_tipping_preserves_per_recipient_isolation() {
 
     for _ in 0..rounds {
         for r in &recipients {
-            client.send_tip(r, &amount);
+            client.send_tip(&tipper, r, &token, &amount);
         }
     }
 
     for r in &recipients {
         assert_eq!(
-            client.get_tips(r),
+            client.get_tips(r, &token),
             amount * rounds as i128,
             "I10: each recipient must have exactly rounds * amount"
         );
@@ -477,15 +538,17 @@ fn round_robin_tipping_preserves_per_recipient_isolation() {
 #[test]
 fn send_tip_and_proof_none_identical_invariant() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
 
-    client.send_tip(&r1, &77i128);
-    client.send_tip_with_proof(&r2, &77i128, &None);
+    client.send_tip(&tipper, &r1, &token, &77i128);
+    client.send_tip_with_proof(&tipper, &r2, &token, &77i128, &None);
 
     assert_eq!(
-        client.get_tips(&r1),
-        client.get_tips(&r2),
+        client.get_tips(&r1, &token),
+        client.get_tips(&r2, &token),
         "I11: send_tip and send_tip_with_proof(None) must produce identical totals"
     );
 
@@ -505,19 +568,21 @@ fn send_tip_and_proof_none_identical_invariant() {
 #[test]
 fn global_tip_count_coherent_with_nonce() {
     let (env, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
 
     // Establish pre-migration nonce
-    client.send_tip(&recipient, &1i128);
-    client.send_tip(&recipient, &1i128);
+    client.send_tip(&tipper, &recipient, &token, &1i128);
+    client.send_tip(&tipper, &recipient, &token, &1i128);
     let nonce_at_migration = client.latest_settlement_nonce();
 
-    client.migrate(&owner);
+    client.migrate(&owner, &Vec::new(&env));
 
     // Post-migration tips
     let post_tips = 7u64;
     for _ in 0..post_tips {
-        client.send_tip(&recipient, &1i128);
+        client.send_tip(&tipper, &recipient, &token, &1i128);
     }
 
     assert_eq!(
@@ -536,11 +601,13 @@ fn global_tip_count_coherent_with_nonce() {
 #[test]
 fn global_tip_count_not_incremented_by_failed_tip_post_migration() {
     let (env, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
-    client.migrate(&owner);
+    client.migrate(&owner, &Vec::new(&env));
 
     // Failed tip
-    let _ = client.try_send_tip(&recipient, &0i128);
+    let _ = client.try_send_tip(&tipper, &recipient, &token, &0i128);
     assert_eq!(
         client.global_tip_count(),
         0,
@@ -548,6 +615,123 @@ fn global_tip_count_not_incremented_by_failed_tip_post_migration() {
     );
 }
 
+// ── I13: Escrow solvency ──────────────────────────────────────────────────────
+
+/// Every escrowed tip is backed by tokens held by the contract, through any
+/// mix of tips and partial withdrawals.
+#[test]
+fn escrow_balance_equals_sum_of_claimable() {
+    let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let balance = TokenClient::new(&env, &token);
+    let tipper = tipper(&env, &client);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let check = |step: &str| {
+        let claimable = client.get_claimable(&alice, &token) + client.get_claimable(&bob, &token);
+        assert_eq!(
+            balance.balance(&client.address),
+            claimable,
+            "I13: contract balance must equal claimable balances after {step}"
+        );
+    };
+
+    client.send_tip(&tipper, &alice, &token, &100i128);
+    client.send_tip(&tipper, &bob, &token, &40i128);
+    check("tips");
+    client.withdraw(&alice, &token, &30i128);
+    check("partial withdrawal");
+    client.send_tip(&tipper, &alice, &token, &5i128);
+    client.withdraw(&bob, &token, &40i128);
+    check("full withdrawal");
+    assert_eq!(balance.balance(&alice), 30);
+    assert_eq!(client.get_tips(&alice, &token), 105);
+}
+
+// ── I14: Refund consistency ───────────────────────────────────────────────────
+
+/// Refunding escrowed tips releases exactly what they escrowed, leaves
+/// recipient totals alone and removes them from GlobalTipCount.
+#[test]
+fn refunds_keep_escrow_and_tip_count_consistent() {
+    let (env, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let balance = TokenClient::new(&env, &token);
+    let tipper = tipper(&env, &client);
+    let alice = Address::generate(&env);
+    let commitment = BytesN::from_array(&env, &[9; 32]);
+    client.migrate(&owner, &Vec::new(&env));
+
+    client.send_tip(&tipper, &alice, &token, &100i128);
+    let refunded = client.send_tip_to_commitment(
+        &tipper,
+        &commitment,
+        &token,
+        &40i128,
+        &refund_commitment(&env, &tipper),
+    );
+    client.send_tip_to_commitment(
+        &tipper,
+        &commitment,
+        &token,
+        &2i128,
+        &refund_commitment(&env, &tipper),
+    );
+    assert_eq!(client.global_tip_count(), 3);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += AnonymousTipping::ESCROW_CLAIM_WINDOW_SECONDS);
+    client.refund_tip(&refunded, &tipper, &refund_salt(&env));
+
+    assert_eq!(
+        client.global_tip_count(),
+        2,
+        "I14: a refunded tip must stop counting"
+    );
+    assert_eq!(
+        balance.balance(&client.address),
+        client.get_claimable(&alice, &token) + client.get_escrowed(&commitment, &token),
+        "I14: contract balance must equal claimable plus escrowed after a refund"
+    );
+    assert_eq!(
+        client.get_tips(&alice, &token),
+        100,
+        "I14: refunds must not touch recipient totals"
+    );
+    assert_eq!(client.latest_settlement_nonce(), 3);
+}
+
+/// Tips escrowed before `migrate()` were never counted, so refunding them
+/// must leave GlobalTipCount alone.
+#[test]
+fn refunding_a_pre_migration_tip_keeps_tip_count() {
+    let (env, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
+    let commitment = BytesN::from_array(&env, &[9; 32]);
+
+    let early = client.send_tip_to_commitment(
+        &tipper,
+        &commitment,
+        &token,
+        &40i128,
+        &refund_commitment(&env, &tipper),
+    );
+    client.migrate(&owner, &Vec::new(&env));
+    client.send_tip(&tipper, &Address::generate(&env), &token, &1i128);
+    assert_eq!(client.global_tip_count(), 1);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += AnonymousTipping::ESCROW_CLAIM_WINDOW_SECONDS);
+    client.refund_tip(&early, &tipper, &refund_salt(&env));
+    assert_eq!(
+        client.global_tip_count(),
+        1,
+        "I14: refunding an uncounted tip must not uncount another"
+    );
+}
+
 // ── adversarial precision: rounding boundary for proof metadata ───────────────
 
 /// The metadata length check is exact: 128 bytes succeeds, 129 bytes fails.
@@ -555,20 +739,23 @@ fn global_tip_count_not_incremented_by_failed_tip_post_migration() {
 #[test]
 fn metadata_length_boundary_exact() {
     let (env, client) = setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let recipient = Address::generate(&env);
     let max = anonymous_tipping::AnonymousTipping::MAX_PROOF_METADATA_LEN as usize;
 
     // Exactly at the boundary — must succeed
     let ok_meta = SorobanString::from_str(&env, &std::string::String::from("x").repeat(max));
-    let sid = client.send_tip_with_proof(&recipient, &1i128, &Some(ok_meta));
+    let sid = client.send_tip_with_proof(&tipper, &recipient, &token, &1i128, &Some(ok_meta));
     assert_eq!(sid, 1);
 
     // One over the boundary — must fail
     let over_meta = SorobanString::from_str(&env, &std::string::String::from("x").repeat(max + 1));
-    let result = client.try_send_tip_with_proof(&recipient, &1i128, &Some(over_meta));
+    let result =
+        client.try_send_tip_with_proof(&tipper, &recipient, &token, &1i128, &Some(over_meta));
     assert_eq!(result, Err(Ok(Error::MetadataTooLong)));
 
     // Total must only reflect the one successful tip
-    assert_eq!(client.get_tips(&recipient), 1i128);
+    assert_eq!(client.get_tips(&recipient, &token), 1i128);
     assert_eq!(client.latest_settlement_nonce(), 1);
 }
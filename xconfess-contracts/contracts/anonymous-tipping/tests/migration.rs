@@ -1,6 +1,7 @@
 //! Migration tests for the anonymous-tipping contract.
 //!
-//! Each test exercises a specific aspect of the v1→v2 schema migration:
+//! Each test exercises a specific aspect of the v1→v2 and v2→v3 schema
+//! migrations:
 //!   - fresh contract (never init'd), already-migrated contracts, idempotency,
 //!     fixture states with live data, rollback properties, and auth enforcement.
 //!
@@ -12,9 +13,12 @@
 extern crate std;
 
 use anonymous_tipping::{
-    AnonymousTipping, AnonymousTippingClient, SCHEMA_VERSION_CURRENT, SCHEMA_VERSION_INITIAL,
+    testutils::tipper, AnonymousTipping, AnonymousTippingClient, SCHEMA_VERSION_CURRENT,
+    SCHEMA_VERSION_INITIAL,
+};
+use soroban_sdk::{
+    testutils::Address as _, vec, Address, Env, String as SorobanString, Symbol, Vec,
 };
-use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString};
 
 // ── helpers ───────────────────────────────────────────────────────────────────
 
@@ -23,7 +27,11 @@ fn setup() -> (Env, Address, AnonymousTippingClient<'static>) {
     env.mock_all_auths();
     let id = env.register(AnonymousTipping, ());
     let client = AnonymousTippingClient::new(&env, &id);
-    client.init();
+    let token_admin = Address::generate(&env);
+    client.init(
+        &env.register_stellar_asset_contract_v2(token_admin)
+            .address(),
+    );
     (env, id, client)
 }
 
@@ -34,6 +42,14 @@ fn owner_setup() -> (Env, Address, Address, AnonymousTippingClient<'static>) {
     (env, id, owner, client)
 }
 
+/// Store a pre-v3 lifetime total for `recipient`. The legacy key is the
+/// contract's `DataKey::RecipientTotal(recipient)`, encoded as
+/// `[Symbol("RecipientTotal"), recipient]`.
+fn write_legacy_total(env: &Env, id: &Address, recipient: &Address, total: i128) {
+    let key = (Symbol::new(env, "RecipientTotal"), recipient.clone());
+    env.as_contract(id, || env.storage().persistent().set(&key, &total));
+}
+
 // ── schema version defaults ───────────────────────────────────────────────────
 
 #[test]
@@ -60,9 +76,9 @@ fn global_tip_count_is_zero_before_migration() {
 
 #[test]
 fn migrate_bumps_schema_version_to_current() {
-    let (_env, _id, owner, client) = owner_setup();
+    let (env, _id, owner, client) = owner_setup();
 
-    let new_version = client.migrate(&owner);
+    let new_version = client.migrate(&owner, &Vec::new(&env));
     assert_eq!(
         new_version, SCHEMA_VERSION_CURRENT,
         "migrate() must return SCHEMA_VERSION_CURRENT after upgrade"
@@ -76,8 +92,8 @@ fn migrate_bumps_schema_version_to_current() {
 
 #[test]
 fn migrate_initialises_global_tip_count_to_zero() {
-    let (_env, _id, owner, client) = owner_setup();
-    client.migrate(&owner);
+    let (env, _id, owner, client) = owner_setup();
+    client.migrate(&owner, &Vec::new(&env));
 
     assert_eq!(
         client.global_tip_count(),
@@ -93,26 +109,28 @@ fn migrate_initialises_global_tip_count_to_zero() {
 #[test]
 fn migration_preserves_pre_existing_recipient_totals() {
     let (env, _id, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let alice = Address::generate(&env);
     let bob = Address::generate(&env);
 
     // Send tips before migration (fixture B state)
-    let id1 = client.send_tip(&alice, &100i128);
-    let id2 = client.send_tip(&alice, &50i128);
-    let id3 = client.send_tip(&bob, &200i128);
+    let id1 = client.send_tip(&tipper, &alice, &token, &100i128);
+    let id2 = client.send_tip(&tipper, &alice, &token, &50i128);
+    let id3 = client.send_tip(&tipper, &bob, &token, &200i128);
     assert_eq!(id1, 1);
     assert_eq!(id2, 2);
     assert_eq!(id3, 3);
 
-    client.migrate(&owner);
+    client.migrate(&owner, &Vec::new(&env));
 
     assert_eq!(
-        client.get_tips(&alice),
+        client.get_tips(&alice, &token),
         150i128,
         "alice's total must survive migration"
     );
     assert_eq!(
-        client.get_tips(&bob),
+        client.get_tips(&bob, &token),
         200i128,
         "bob's total must survive migration"
     );
@@ -128,14 +146,16 @@ fn migration_preserves_pre_existing_recipient_totals() {
 #[test]
 fn global_tip_count_starts_at_zero_post_migration_not_backfilled() {
     let (env, _id, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let alice = Address::generate(&env);
 
     // 3 tips before migration
-    client.send_tip(&alice, &10i128);
-    client.send_tip(&alice, &10i128);
-    client.send_tip(&alice, &10i128);
+    client.send_tip(&tipper, &alice, &token, &10i128);
+    client.send_tip(&tipper, &alice, &token, &10i128);
+    client.send_tip(&tipper, &alice, &token, &10i128);
 
-    client.migrate(&owner);
+    client.migrate(&owner, &Vec::new(&env));
 
     assert_eq!(
         client.global_tip_count(),
@@ -144,8 +164,8 @@ fn global_tip_count_starts_at_zero_post_migration_not_backfilled() {
     );
 
     // 2 tips after migration
-    client.send_tip(&alice, &5i128);
-    client.send_tip(&alice, &5i128);
+    client.send_tip(&tipper, &alice, &token, &5i128);
+    client.send_tip(&tipper, &alice, &token, &5i128);
 
     assert_eq!(
         client.global_tip_count(),
@@ -159,11 +179,13 @@ fn global_tip_count_starts_at_zero_post_migration_not_backfilled() {
 #[test]
 fn global_tip_count_increments_by_one_per_successful_tip() {
     let (env, _id, owner, client) = owner_setup();
-    client.migrate(&owner);
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
+    client.migrate(&owner, &Vec::new(&env));
 
     let recipient = Address::generate(&env);
     for expected in 1u64..=5 {
-        client.send_tip(&recipient, &1i128);
+        client.send_tip(&tipper, &recipient, &token, &1i128);
         assert_eq!(
             client.global_tip_count(),
             expected,
@@ -177,14 +199,16 @@ fn global_tip_count_increments_by_one_per_successful_tip() {
 #[test]
 fn global_tip_count_spans_multiple_recipients() {
     let (env, _id, owner, client) = owner_setup();
-    client.migrate(&owner);
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
+    client.migrate(&owner, &Vec::new(&env));
 
     let alice = Address::generate(&env);
     let bob = Address::generate(&env);
 
-    client.send_tip(&alice, &1i128);
-    client.send_tip(&bob, &1i128);
-    client.send_tip(&alice, &1i128);
+    client.send_tip(&tipper, &alice, &token, &1i128);
+    client.send_tip(&tipper, &bob, &token, &1i128);
+    client.send_tip(&tipper, &alice, &token, &1i128);
 
     assert_eq!(client.global_tip_count(), 3);
 }
@@ -192,12 +216,14 @@ fn global_tip_count_spans_multiple_recipients() {
 #[test]
 fn global_tip_count_not_incremented_by_failed_tip() {
     let (env, _id, owner, client) = owner_setup();
-    client.migrate(&owner);
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
+    client.migrate(&owner, &Vec::new(&env));
 
     let recipient = Address::generate(&env);
 
     // Failed tip (invalid amount) must not increment counter
-    let _ = client.try_send_tip(&recipient, &0i128);
+    let _ = client.try_send_tip(&tipper, &recipient, &token, &0i128);
     assert_eq!(
         client.global_tip_count(),
         0,
@@ -205,7 +231,7 @@ fn global_tip_count_not_incremented_by_failed_tip() {
     );
 
     // Successful tip increments
-    client.send_tip(&recipient, &1i128);
+    client.send_tip(&tipper, &recipient, &token, &1i128);
     assert_eq!(client.global_tip_count(), 1);
 }
 
@@ -218,7 +244,7 @@ fn migration_preserves_pause_state() {
     client.pause(&owner, &reason);
     assert!(client.is_paused());
 
-    client.migrate(&owner);
+    client.migrate(&owner, &Vec::new(&env));
 
     assert!(
         client.is_paused(),
@@ -233,10 +259,10 @@ fn migration_preserves_pause_state() {
 
 #[test]
 fn migration_preserves_rate_limit_config() {
-    let (_env, _id, owner, client) = owner_setup();
+    let (env, _id, owner, client) = owner_setup();
     // Custom rate-limit (overrides the default set by configure_controls)
     client.configure_controls(&owner, &25u32, &300u64);
-    client.migrate(&owner);
+    client.migrate(&owner, &Vec::new(&env));
 
     let cfg = client.get_rate_limit_config();
     assert_eq!(
@@ -254,16 +280,18 @@ fn migration_preserves_rate_limit_config() {
 #[test]
 fn migrate_is_idempotent() {
     let (env, _id, owner, client) = owner_setup();
-    client.migrate(&owner);
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
+    client.migrate(&owner, &Vec::new(&env));
 
     // Accumulate some state after first migration
     let recipient = Address::generate(&env);
-    client.send_tip(&recipient, &77i128);
+    client.send_tip(&tipper, &recipient, &token, &77i128);
     let count_after_first = client.global_tip_count();
     assert_eq!(count_after_first, 1);
 
     // Second migrate must be a no-op
-    let version_again = client.migrate(&owner);
+    let version_again = client.migrate(&owner, &Vec::new(&env));
     assert_eq!(version_again, SCHEMA_VERSION_CURRENT);
     assert_eq!(
         client.global_tip_count(),
@@ -271,7 +299,7 @@ fn migrate_is_idempotent() {
         "second migrate() must not reset GlobalTipCount"
     );
     assert_eq!(
-        client.get_tips(&recipient),
+        client.get_tips(&recipient, &token),
         77i128,
         "second migrate() must not alter recipient totals"
     );
@@ -279,9 +307,9 @@ fn migrate_is_idempotent() {
 
 #[test]
 fn migrate_multiple_times_returns_current_version_each_time() {
-    let (_env, _id, owner, client) = owner_setup();
+    let (env, _id, owner, client) = owner_setup();
     for _ in 0..3 {
-        let v = client.migrate(&owner);
+        let v = client.migrate(&owner, &Vec::new(&env));
         assert_eq!(v, SCHEMA_VERSION_CURRENT);
     }
     assert_eq!(client.schema_version(), SCHEMA_VERSION_CURRENT);
@@ -295,7 +323,7 @@ fn migrate_requires_owner_authorization() {
     let (env, _id, _owner, client) = owner_setup();
     let non_owner = Address::generate(&env);
 
-    let result = client.try_migrate(&non_owner);
+    let result = client.try_migrate(&non_owner, &Vec::new(&env));
     assert_eq!(
         result,
         Err(Ok(Error::Unauthorized)),
@@ -312,19 +340,21 @@ fn migrate_requires_owner_authorization() {
 #[test]
 fn v2_migration_does_not_modify_or_remove_v1_keys() {
     let (env, _id, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let tipper = tipper(&env, &client);
     let alice = Address::generate(&env);
 
     // Capture v1 state
-    client.send_tip(&alice, &500i128);
+    client.send_tip(&tipper, &alice, &token, &500i128);
     let pre_nonce = client.latest_settlement_nonce();
-    let pre_total = client.get_tips(&alice);
+    let pre_total = client.get_tips(&alice, &token);
     let pre_rate_cfg = client.get_rate_limit_config();
 
-    client.migrate(&owner);
+    client.migrate(&owner, &Vec::new(&env));
 
     // v1 keys must be byte-for-byte identical after migration
     assert_eq!(client.latest_settlement_nonce(), pre_nonce);
-    assert_eq!(client.get_tips(&alice), pre_total);
+    assert_eq!(client.get_tips(&alice, &token), pre_total);
     let post_cfg = client.get_rate_limit_config();
     assert_eq!(
         post_cfg.max_tips_per_window,
@@ -332,3 +362,45 @@ fn v2_migration_does_not_modify_or_remove_v1_keys() {
     );
     assert_eq!(post_cfg.window_seconds, pre_rate_cfg.window_seconds);
 }
+
+// ── v2 → v3 recipient totals ──────────────────────────────────────────────────
+
+#[test]
+fn migrate_folds_legacy_totals_into_the_bound_token() {
+    let (env, id, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    write_legacy_total(&env, &id, &alice, 700);
+    write_legacy_total(&env, &id, &bob, 40);
+    client.send_tip(&tipper(&env, &client), &bob, &token, &10i128);
+
+    client.migrate(&owner, &vec![&env, alice.clone(), bob.clone()]);
+    assert_eq!(client.get_tips(&alice, &token), 700);
+    assert_eq!(client.get_tips(&bob, &token), 50);
+
+    // The legacy keys are gone, so a repeat call does not add them again.
+    client.migrate(&owner, &vec![&env, alice.clone(), bob.clone()]);
+    assert_eq!(client.get_tips(&alice, &token), 700);
+    assert_eq!(client.get_tips(&bob, &token), 50);
+    let key = (Symbol::new(&env, "RecipientTotal"), alice);
+    env.as_contract(&id, || assert!(!env.storage().persistent().has(&key)));
+}
+
+#[test]
+fn unfolded_legacy_total_is_counted_and_folded_by_the_next_tip() {
+    let (env, id, owner, client) = owner_setup();
+    let token = client.get_token().unwrap();
+    let alice = Address::generate(&env);
+    client.migrate(&owner, &Vec::new(&env));
+    write_legacy_total(&env, &id, &alice, 300);
+
+    assert_eq!(client.get_tips(&alice, &token), 300);
+    let other = Address::generate(&env);
+    assert_eq!(client.get_tips(&alice, &other), 0);
+
+    client.send_tip(&tipper(&env, &client), &alice, &token, &25i128);
+    assert_eq!(client.get_tips(&alice, &token), 325);
+    client.migrate(&owner, &vec![&env, alice.clone()]);
+    assert_eq!(client.get_tips(&alice, &token), 325);
+}
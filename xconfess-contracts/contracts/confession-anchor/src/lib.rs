@@ -134,6 +134,10 @@ pub enum Error {
     AlreadyOperator = 13,
     NotOperator = 14,
     IncompatibleUpgrade = 15,
+    AlreadyGuardian = 16,
+    NotGuardian = 17,
+    InvalidPauseDuration = 18,
+    InvalidBreakerConfig = 19,
 }
 
 impl From<access_control::AccessError> for Error {
@@ -149,6 +153,8 @@ impl From<access_control::AccessError> for Error {
             access_control::AccessError::InvalidOwnershipTransfer => Self::InvalidOwnershipTransfer,
             access_control::AccessError::AlreadyOperator => Self::AlreadyOperator,
             access_control::AccessError::NotOperator => Self::NotOperator,
+            access_control::AccessError::AlreadyGuardian => Self::AlreadyGuardian,
+            access_control::AccessError::NotGuardian => Self::NotGuardian,
         }
     }
 }
@@ -160,6 +166,11 @@ impl From<emergency_pause::errors::PauseError> for Error {
             emergency_pause::errors::PauseError::NotPaused => Self::NotPaused,
             emergency_pause::errors::PauseError::Unauthorized => Self::Unauthorized,
             emergency_pause::errors::PauseError::ContractPaused => Self::ContractPaused,
+            emergency_pause::errors::PauseError::InvalidDuration => Self::InvalidPauseDuration,
+            emergency_pause::errors::PauseError::InvalidBreakerConfig => Self::InvalidBreakerConfig,
+            // The anchor has no governance, so none of its pauses can be
+            // governance pauses.
+            emergency_pause::errors::PauseError::GovernancePause => Self::Unauthorized,
         }
     }
 }
@@ -206,7 +217,8 @@ impl ConfessionAnchor {
     /// - panics with error code 4 (ContractPaused) if contract is paused
     pub fn anchor_confession(env: Env, hash: BytesN<32>, timestamp: u64) -> Symbol {
         // Check if paused — use shared emergency pause module
-        emergency_pause::assert_not_paused(&env).unwrap_or_else(|err| panic!("{}", err as u32));
+        emergency_pause::assert_not_paused(&env, emergency_pause::PauseScope::Anchor)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
 
         let storage = get_confession_store(&env);
 
@@ -248,6 +260,12 @@ impl ConfessionAnchor {
         }
         .publish(&env);
 
+        emergency_pause::record_breaker_write(
+            &env,
+            emergency_pause::BreakerMetric::Anchors,
+            emergency_pause::PauseScope::Anchor,
+        );
+
         symbol_short!("anchored")
     }
 
@@ -510,37 +528,97 @@ impl ConfessionAnchor {
     // Pause/Resume Management
     // ─────────────────────────────────────────────────────────────────────────
 
-    /// Pause the contract (owner/admin). Blocks anchor_confession writes.
+    /// Pause the contract (owner/admin/guardian) for up to
+    /// `MAX_PAUSE_DURATION_SECONDS`. Blocks anchor_confession writes.
     /// Read operations (verify, count) remain available.
-    pub fn pause(env: Env, caller: Address, reason: String) -> Result<(), Error> {
-        access_control::require_admin_or_owner(&env, &caller).map_err(Error::from)?;
-
-        if emergency_pause::is_paused(&env) {
-            return Err(Error::AlreadyPaused);
+    pub fn pause(
+        env: Env,
+        caller: Address,
+        reason: String,
+        duration_seconds: u64,
+    ) -> Result<(), Error> {
+        if access_control::is_guardian(&env, &caller) {
+            caller.require_auth();
+        } else {
+            access_control::require_admin_or_owner(&env, &caller).map_err(Error::from)?;
         }
-
-        emergency_pause::set_paused_internal(&env, true);
-        emergency_pause::events::emit_paused(&env, &caller, reason);
-        Ok(())
+        emergency_pause::pause_as(&env, caller, reason, duration_seconds).map_err(Into::into)
     }
 
     /// Unpause the contract (owner/admin).
     pub fn unpause(env: Env, caller: Address, reason: String) -> Result<(), Error> {
         access_control::require_admin_or_owner(&env, &caller).map_err(Error::from)?;
-
-        if !emergency_pause::is_paused(&env) {
-            return Err(Error::NotPaused);
-        }
-
-        emergency_pause::set_paused_internal(&env, false);
-        emergency_pause::events::emit_unpaused(&env, &caller, reason);
-        Ok(())
+        emergency_pause::unpause_as(&env, caller, reason).map_err(Into::into)
     }
 
     /// Check if the contract is paused.
     pub fn is_paused(env: Env) -> bool {
         emergency_pause::is_paused(&env)
     }
+
+    /// Global pause flag and when it lapses.
+    pub fn get_pause_state(env: Env) -> emergency_pause::PauseState {
+        emergency_pause::get_pause_state(&env)
+    }
+
+    /// Pause actions, oldest first.
+    pub fn pause_history(
+        env: Env,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> emergency_pause::PauseHistoryPage {
+        emergency_pause::pause_history(&env, cursor, limit)
+    }
+
+    /// Grant the pause-only guardian role (owner/admin).
+    pub fn grant_guardian(env: Env, caller: Address, target: Address) -> Result<(), Error> {
+        access_control::grant_guardian(&env, &caller, &target).map_err(Into::into)
+    }
+
+    /// Revoke the guardian role (owner/admin).
+    pub fn revoke_guardian(env: Env, caller: Address, target: Address) -> Result<(), Error> {
+        access_control::revoke_guardian(&env, &caller, &target).map_err(Into::into)
+    }
+
+    /// Pause only anchoring writes (owner/admin/guardian); see
+    /// `emergency_pause::PauseScope`.
+    pub fn pause_anchoring(
+        env: Env,
+        caller: Address,
+        reason: String,
+        duration_seconds: u64,
+    ) -> Result<(), Error> {
+        emergency_pause::pause_scope(
+            &env,
+            &caller,
+            emergency_pause::PauseScope::Anchor,
+            reason,
+            duration_seconds,
+        )
+        .map_err(Into::into)
+    }
+
+    /// Lift an anchoring-only pause (owner/admin).
+    pub fn unpause_anchoring(env: Env, caller: Address, reason: String) -> Result<(), Error> {
+        emergency_pause::unpause_scope(&env, &caller, emergency_pause::PauseScope::Anchor, reason)
+            .map_err(Into::into)
+    }
+
+    /// Set the anchoring circuit breaker (owner/admin). More than
+    /// `max_writes_per_window` anchors in one window pauses anchoring for
+    /// `trip_duration_seconds`.
+    pub fn configure_circuit_breaker(
+        env: Env,
+        caller: Address,
+        config: emergency_pause::BreakerConfig,
+    ) -> Result<(), Error> {
+        emergency_pause::configure_circuit_breaker(&env, &caller, &config).map_err(Into::into)
+    }
+
+    /// Current circuit breaker thresholds (all zero when disabled).
+    pub fn get_circuit_breaker_config(env: Env) -> emergency_pause::BreakerConfig {
+        emergency_pause::circuit_breaker::get_config(&env)
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1190,12 +1268,12 @@ mod test {
 
         // Owner/admin only: pause and unpause.
         let pause_reason = SorobanString::from_str(&env, "maintenance");
-        client.pause(&admin, &pause_reason);
+        client.pause(&admin, &pause_reason, &3_600);
         client.unpause(&owner, &pause_reason);
 
         // Operator cannot execute owner/admin-only actions.
         assert_eq!(
-            client.try_pause(&operator, &pause_reason),
+            client.try_pause(&operator, &pause_reason, &3_600),
             Err(Ok(Error::NotAuthorized))
         );
         assert_eq!(
@@ -1209,7 +1287,7 @@ mod test {
 
         // Outsider cannot run privileged actions.
         assert_eq!(
-            client.try_pause(&outsider, &pause_reason),
+            client.try_pause(&outsider, &pause_reason, &3_600),
             Err(Ok(Error::NotAuthorized))
         );
     }
@@ -1296,7 +1374,7 @@ mod test {
 
         client.initialize(&owner);
 
-        client.pause(&owner, &reason);
+        client.pause(&owner, &reason, &3_600);
     }
 
     #[test]
@@ -1311,6 +1389,120 @@ mod test {
 
         client.initialize(&owner);
 
-        client.pause(&owner, &reason);
+        client.pause(&owner, &reason, &3_600);
+    }
+
+    #[test]
+    fn anchoring_scope_pause_blocks_anchor_only() {
+        let (env, client) = new_client();
+        let owner = Address::generate(&env);
+        let reason = SorobanString::from_str(&env, "spam incident");
+        client.initialize(&owner);
+
+        client.pause_anchoring(&owner, &reason, &3_600);
+
+        assert!(!client.is_paused());
+        assert!(client
+            .try_anchor_confession(&sample_hash(&env, 1), &1)
+            .is_err());
+        assert_eq!(
+            client.try_pause_anchoring(&owner, &reason, &3_600),
+            Err(Ok(Error::AlreadyPaused))
+        );
+
+        client.unpause_anchoring(&owner, &reason);
+        client.anchor_confession(&sample_hash(&env, 1), &1);
+    }
+
+    #[test]
+    fn guardian_can_pause_but_not_unpause() {
+        let (env, client) = new_client();
+        let owner = Address::generate(&env);
+        let guardian = Address::generate(&env);
+        let reason = SorobanString::from_str(&env, "incident");
+        client.initialize(&owner);
+        client.grant_guardian(&owner, &guardian);
+
+        client.pause(&guardian, &reason, &3_600);
+
+        assert!(client.is_paused());
+        assert_eq!(
+            client.try_unpause(&guardian, &reason),
+            Err(Ok(Error::NotAuthorized))
+        );
+        assert_eq!(client.pause_history(&None, &10).items.len(), 1);
+    }
+
+    fn breaker_config(max_writes: u32) -> emergency_pause::BreakerConfig {
+        emergency_pause::BreakerConfig {
+            max_writes_per_window: max_writes,
+            window_ledgers: 10,
+            trip_duration_seconds: 3_600,
+        }
+    }
+
+    #[test]
+    fn circuit_breaker_trips_anchor_scope_on_spike() {
+        let (env, client) = new_client();
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.configure_circuit_breaker(&owner, &breaker_config(2));
+
+        client.anchor_confession(&sample_hash(&env, 1), &1);
+        client.anchor_confession(&sample_hash(&env, 2), &2);
+        // The third anchor crosses the threshold: it lands, then anchoring pauses.
+        client.anchor_confession(&sample_hash(&env, 3), &3);
+
+        assert_eq!(client.get_confession_count(), 3);
+        assert!(!client.is_paused());
+        assert!(client
+            .try_anchor_confession(&sample_hash(&env, 4), &4)
+            .is_err());
+
+        let history = client.pause_history(&None, &10);
+        assert_eq!(history.items.len(), 1);
+        assert_eq!(
+            history.items.get(0).unwrap().target,
+            emergency_pause::PauseTarget::Scope(emergency_pause::PauseScope::Anchor)
+        );
+        assert_eq!(history.items.get(0).unwrap().actor, client.address);
+    }
+
+    #[test]
+    fn circuit_breaker_window_resets_after_window_ledgers() {
+        let (env, client) = new_client();
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.configure_circuit_breaker(&owner, &breaker_config(2));
+
+        client.anchor_confession(&sample_hash(&env, 1), &1);
+        client.anchor_confession(&sample_hash(&env, 2), &2);
+        advance_ledger(&env, 10);
+        client.anchor_confession(&sample_hash(&env, 3), &3);
+        client.anchor_confession(&sample_hash(&env, 4), &4);
+
+        assert!(client.pause_history(&None, &10).items.is_empty());
+    }
+
+    #[test]
+    fn circuit_breaker_config_is_admin_only_and_validated() {
+        let (env, client) = new_client();
+        let owner = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        client.initialize(&owner);
+
+        assert_eq!(
+            client.try_configure_circuit_breaker(&outsider, &breaker_config(5)),
+            Err(Ok(Error::Unauthorized))
+        );
+        let mut no_window = breaker_config(5);
+        no_window.window_ledgers = 0;
+        assert_eq!(
+            client.try_configure_circuit_breaker(&owner, &no_window),
+            Err(Ok(Error::InvalidBreakerConfig))
+        );
+
+        client.configure_circuit_breaker(&owner, &breaker_config(5));
+        assert_eq!(client.get_circuit_breaker_config(), breaker_config(5));
     }
 }
@@ -215,7 +215,11 @@ fn migration_preserves_pause_state() {
     let env = new_env();
     let (owner, client) = owner_client(&env);
 
-    client.pause(&owner, &SorobanString::from_str(&env, "maintenance"));
+    client.pause(
+        &owner,
+        &SorobanString::from_str(&env, "maintenance"),
+        &3_600,
+    );
     assert!(client.is_paused());
 
     // migrate() should succeed even while paused
@@ -0,0 +1,323 @@
+//! Checkpoint summaries and snapshot pages for indexers.
+//!
+//! The registry keeps live counters that are updated on every mutation, so a
+//! reconciliation service can compare a single `get_checkpoint()` digest with
+//! its own view instead of replaying the full event history. Deployments
+//! upgraded from a version without counters start counting from zero until an
+//! admin runs `rebuild_checkpoint_counters`.
+
+use soroban_sdk::{contracttype, xdr::ToXdr, BytesN, Env, Vec};
+
+use crate::{Confession, ConfessionStatus, ConfessionTombstone, DataKey};
+
+/// Bumped whenever the digest input changes shape.
+pub const CHECKPOINT_VERSION: u32 = 3;
+/// Maximum number of IDs examined by one `get_snapshot_page` call.
+pub const MAX_SNAPSHOT_PAGE_LIMIT: u32 = 50;
+/// Maximum number of IDs examined by one `rebuild_counters` call.
+pub const MAX_REBUILD_LIMIT: u32 = 50;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum CheckpointKey {
+    Counters,
+    /// Counter rebuild in progress; see [`rebuild_counters`].
+    Rebuild,
+}
+
+/// Progress of a counter rebuild. `counters` holds the recount of IDs below
+/// `next_id`, plus every change since made to those IDs or to IDs above
+/// `end_id`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CounterRebuild {
+    pub next_id: u64,
+    /// Latest confession ID when the rebuild started.
+    pub end_id: u64,
+    pub counters: RegistryCounters,
+}
+
+/// Live registry counters. Status counters cover stored records only;
+/// erased and purged records move out of them.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegistryCounters {
+    pub total_created: u64,
+    /// Active confessions without an expiry.
+    pub active: u64,
+    /// Active ephemeral confessions, expired or not. They expire without a
+    /// write, so the contract cannot tell the two apart until they are purged.
+    pub ephemeral: u64,
+    pub flagged: u64,
+    pub deleted: u64,
+    pub erased: u64,
+    pub purged: u64,
+    /// Number of confession events emitted (sum of all per-confession nonces).
+    pub events_emitted: u64,
+    /// Number of caller nonces consumed by `_seq` calls, across all callers.
+    /// Not recoverable by `rebuild_counters`.
+    pub nonces_consumed: u64,
+}
+
+/// Snapshot summary for indexer consumption.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckpointSummary {
+    pub version_marker: u32,
+    pub counters: RegistryCounters,
+    pub latest_confession_id: u64,
+    /// Event nonce of `latest_confession_id` (0 if none).
+    pub latest_confession_nonce: u64,
+    /// sha256 over the XDR of
+    /// `(version_marker, counters, latest_confession_id, latest_confession_nonce)`.
+    /// Nonce state enters only through the `events_emitted` and
+    /// `nonces_consumed` totals; individual per-confession event nonces and
+    /// per-caller nonce windows are left out.
+    pub digest: BytesN<32>,
+    /// Ledger the summary was read at. Not part of the digest.
+    pub ledger_sequence: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapshotKind {
+    Confessions,
+    Tombstones,
+}
+
+/// Snapshot page for paginated reading. Only the vector matching the
+/// requested `SnapshotKind` is populated.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotPage {
+    pub confessions: Vec<Confession>,
+    pub tombstones: Vec<ConfessionTombstone>,
+    /// Pass as `cursor` to continue; `None` once the last ID was examined.
+    pub next_cursor: Option<u64>,
+}
+
+// ─── Counter updates ───
+
+pub fn counters(env: &Env) -> RegistryCounters {
+    env.storage()
+        .instance()
+        .get(&CheckpointKey::Counters)
+        .unwrap_or_default()
+}
+
+/// Apply `f` to the live counters and, while a rebuild is running, to the
+/// rebuilt counters too unless the rebuild has yet to reach `id`.
+fn update_counters(env: &Env, id: u64, f: impl Fn(&mut RegistryCounters)) {
+    let mut current = counters(env);
+    f(&mut current);
+    env.storage()
+        .instance()
+        .set(&CheckpointKey::Counters, &current);
+
+    if let Some(mut rebuild) = rebuild_state(env) {
+        if id < rebuild.next_id || id > rebuild.end_id {
+            f(&mut rebuild.counters);
+            env.storage()
+                .instance()
+                .set(&CheckpointKey::Rebuild, &rebuild);
+        }
+    }
+}
+
+fn status_counter<'a>(
+    c: &'a mut RegistryCounters,
+    confession: &Confession,
+    status: &ConfessionStatus,
+) -> &'a mut u64 {
+    match status {
+        ConfessionStatus::Active | ConfessionStatus::Expired if confession.expires_at.is_some() => {
+            &mut c.ephemeral
+        }
+        ConfessionStatus::Active | ConfessionStatus::Expired => &mut c.active,
+        ConfessionStatus::Flagged => &mut c.flagged,
+        ConfessionStatus::Deleted => &mut c.deleted,
+    }
+}
+
+fn leave_status(c: &mut RegistryCounters, confession: &Confession) {
+    let counter = status_counter(c, confession, &confession.status);
+    *counter = counter.saturating_sub(1);
+}
+
+pub fn record_created(env: &Env, confession: &Confession) {
+    update_counters(env, confession.id, |c| {
+        c.total_created += 1;
+        *status_counter(c, confession, &confession.status) += 1;
+    });
+}
+
+/// Record `confession` moving from its stored status to `new`.
+pub fn record_status_change(env: &Env, confession: &Confession, new: &ConfessionStatus) {
+    update_counters(env, confession.id, |c| {
+        leave_status(c, confession);
+        *status_counter(c, confession, new) += 1;
+    });
+}
+
+pub fn record_erased(env: &Env, confession: &Confession) {
+    update_counters(env, confession.id, |c| {
+        leave_status(c, confession);
+        c.erased += 1;
+    });
+}
+
+pub fn record_purged(env: &Env, confession: &Confession) {
+    update_counters(env, confession.id, |c| {
+        leave_status(c, confession);
+        c.purged += 1;
+    });
+}
+
+pub fn record_event(env: &Env, id: u64) {
+    update_counters(env, id, |c| c.events_emitted += 1);
+}
+
+/// Caller nonces are not tied to a confession, so a rebuild never recounts
+/// them; it keeps the live total instead.
+pub fn record_nonce_consumed(env: &Env) {
+    let mut current = counters(env);
+    current.nonces_consumed += 1;
+    env.storage()
+        .instance()
+        .set(&CheckpointKey::Counters, &current);
+}
+
+// ─── Rebuild ───
+
+pub fn rebuild_state(env: &Env) -> Option<CounterRebuild> {
+    env.storage().instance().get(&CheckpointKey::Rebuild)
+}
+
+/// Recount up to `limit` IDs from storage, starting a rebuild if none is
+/// running. Once every ID up to the latest at the start has been examined,
+/// the recount replaces the live counters. Returns the next ID to examine,
+/// or `None` once the rebuild has finished.
+///
+/// An ID with neither a record nor a tombstone is counted as purged.
+/// `nonces_consumed` keeps its live value.
+pub fn rebuild_counters(env: &Env, limit: u32) -> Option<u64> {
+    let mut rebuild = rebuild_state(env).unwrap_or(CounterRebuild {
+        next_id: 1,
+        end_id: latest_confession_id(env),
+        counters: RegistryCounters::default(),
+    });
+    let end = rebuild
+        .next_id
+        .saturating_add(limit.clamp(1, MAX_REBUILD_LIMIT) as u64 - 1)
+        .min(rebuild.end_id);
+
+    let c = &mut rebuild.counters;
+    let mut id = rebuild.next_id;
+    while id <= end {
+        c.total_created += 1;
+        if let Some(confession) = crate::load_confession(env, id) {
+            *status_counter(c, &confession, &confession.status) += 1;
+        } else if env.storage().persistent().has(&DataKey::Tombstone(id)) {
+            c.erased += 1;
+        } else {
+            c.purged += 1;
+        }
+        c.events_emitted += env
+            .storage()
+            .instance()
+            .get::<_, u64>(&DataKey::EventNonceConfession(id))
+            .unwrap_or(0);
+        id += 1;
+    }
+    rebuild.next_id = id;
+
+    if rebuild.next_id > rebuild.end_id {
+        let mut rebuilt = rebuild.counters;
+        rebuilt.nonces_consumed = counters(env).nonces_consumed;
+        env.storage()
+            .instance()
+            .set(&CheckpointKey::Counters, &rebuilt);
+        env.storage().instance().remove(&CheckpointKey::Rebuild);
+        None
+    } else {
+        let next_id = rebuild.next_id;
+        env.storage()
+            .instance()
+            .set(&CheckpointKey::Rebuild, &rebuild);
+        Some(next_id)
+    }
+}
+
+// ─── Reads ───
+
+fn latest_confession_id(env: &Env) -> u64 {
+    let next_id: u64 = env.storage().instance().get(&DataKey::NextId).unwrap_or(1);
+    next_id - 1
+}
+
+/// Build the current checkpoint summary from live storage.
+pub fn get_checkpoint_summary(env: &Env) -> CheckpointSummary {
+    let counters = counters(env);
+    let latest_confession_id = latest_confession_id(env);
+    let latest_confession_nonce: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::EventNonceConfession(latest_confession_id))
+        .unwrap_or(0);
+
+    let digest_input = (
+        CHECKPOINT_VERSION,
+        counters.clone(),
+        latest_confession_id,
+        latest_confession_nonce,
+    );
+    let digest = env.crypto().sha256(&digest_input.to_xdr(env)).into();
+
+    CheckpointSummary {
+        version_marker: CHECKPOINT_VERSION,
+        counters,
+        latest_confession_id,
+        latest_confession_nonce,
+        digest,
+        ledger_sequence: env.ledger().sequence(),
+    }
+}
+
+/// Examine up to `limit` IDs after `cursor` and return the entries of `kind`
+/// stored under them. Pages can therefore be shorter than `limit`.
+pub fn get_snapshot_page(
+    env: &Env,
+    kind: SnapshotKind,
+    cursor: Option<u64>,
+    limit: u32,
+) -> SnapshotPage {
+    let limit = limit.clamp(1, MAX_SNAPSHOT_PAGE_LIMIT) as u64;
+    let last_id = latest_confession_id(env);
+    let start = cursor.unwrap_or(0).saturating_add(1);
+    let end = start.saturating_add(limit - 1).min(last_id);
+
+    let mut confessions = Vec::new(env);
+    let mut tombstones = Vec::new(env);
+    let mut id = start;
+    while id <= end {
+        match kind {
+            SnapshotKind::Confessions => {
+                if let Some(confession) = crate::load_confession(env, id) {
+                    confessions.push_back(crate::with_read_status(env, confession));
+                }
+            }
+            SnapshotKind::Tombstones => {
+                if let Some(tombstone) = env.storage().persistent().get(&DataKey::Tombstone(id)) {
+                    tombstones.push_back(tombstone);
+                }
+            }
+        }
+        id += 1;
+    }
+
+    SnapshotPage {
+        confessions,
+        tombstones,
+        next_cursor: if end < last_id { Some(end) } else { None },
+    }
+}
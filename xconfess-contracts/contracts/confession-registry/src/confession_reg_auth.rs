@@ -38,10 +38,71 @@
 //     F3  admin can update then delete in sequence
 //     F4  updated_at is set correctly on update and delete
 //     F5  author index is unchanged by status updates and deletes
-
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
-
-use crate::{ConfessionRegistry, ConfessionRegistryClient, ConfessionStatus, ReplayError};
+//
+//   Block G – sequenced replay protection
+//     G1  replayed nonce is rejected
+//     G2  stale nonce is rejected after a successful mutation
+//     G3  nonces inside the window are accepted out of order, once each
+//     G4  nonce beyond the window is rejected until the window slides
+//     G5  expired request is rejected without consuming the nonce
+//     G6  legacy CallerNonce state seeds the window
+//
+//   Block H – scoped pauses
+//     H1  pausing the create scope leaves update and delete available
+//     H2  global pause overrides an unpaused scope
+//     H3  unpausing a scope does not lift the global pause
+//     H4  double pause / unpause of a scope is rejected
+//     H5  only owner/admin can pause a scope
+//
+//   Block I – guardians and expiring pauses
+//     I1  guardian can pause but cannot unpause
+//     I2  a pause lapses once its duration has passed
+//     I3  durations of zero or beyond the maximum are rejected
+//     I4  governance pauses lapse after their duration, capped like direct pauses
+//     I5  pause_history records pauses and early lifts with their origin
+//     I6  only governance can lift a governance pause
+//
+//   Block J – circuit breaker
+//     J1  a confession spike pauses the create scope only
+//     J2  a breaker pause lapses after its trip duration
+//     J3  only owner/admin can configure; invalid configs are rejected
+//
+//   Block K – author suspensions
+//     K1  a suspended author cannot create; other authors can
+//     K2  suspensions lapse on their own
+//     K3  lift_suspension restores creation; lifting twice is rejected
+//     K4  only moderators can suspend, and moderators cannot be suspended
+//     K5  suspended submitters cannot create committed confessions
+//     K6  suspended authors cannot prove authorship of committed confessions
+//
+//   Block L – author rotation
+//     L1  rotate_author moves records and index to the new key
+//     L2  erased slots are dropped and the new key keeps its own confessions
+//     L3  an owner/admin-approved recovery runs only after the delay
+//     L4  rotating to the same key, from a suspended key or to one is rejected
+//     L5  the old key can cancel a pending recovery
+//     L6  indexes above the per-call bound rotate across several calls
+//     L7  pending scheduled confessions follow the rotation
+//     L8  a rotation that would exceed the pending-schedule cap is rejected
+//
+//   Block M – content labels
+//     M1  operators label confessions; labels show in get and list reads
+//     M2  only vocabulary labels can be applied, once each, up to the bound
+//     M3  authors and outsiders cannot label; only admins edit the vocabulary
+//     M4  label changes emit nonced events
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, String, Symbol, Vec,
+};
+
+use crate::{
+    compute_author_commitment,
+    emergency_pause::{PauseAction, PauseOrigin, PauseTarget, MAX_PAUSE_DURATION_SECONDS},
+    BreakerConfig, ConfessionRegistry, ConfessionRegistryClient, ConfessionStatus, DataKey,
+    PauseScope, ReplayError, AUTHOR_RECOVERY_DELAY_SECONDS, MAX_LABELS_PER_CONFESSION,
+    MAX_PENDING_SCHEDULES_PER_AUTHOR, MAX_ROTATION_INDEX_LEN, NONCE_WINDOW_SIZE,
+};
 
 // ─── Helpers ──────────────────────────────────────────────────────────────────
 
@@ -67,7 +128,7 @@ fn h(env: &Env, seed: u8) -> BytesN<32> {
 
 /// Create a confession and return its ID.
 fn create(client: &ConfessionRegistryClient, env: &Env, author: &Address, seed: u8) -> u64 {
-    client.create_confession(author, &h(env, seed), &1_000_000)
+    client.create_confession(author, &h(env, seed), &1_000_000, &None)
 }
 
 /// Pause the contract through the governance flow.
@@ -92,7 +153,7 @@ fn a1_author_can_delete_own_confession() {
     let (env, client, _admin, author) = setup();
     let id = create(&client, &env, &author, 1);
 
-    client.delete_confession(&author, &id, &2_000_000);
+    client.delete_confession(&author, &id, &2_000_000, &None);
 
     let conf = client.get_confession(&id);
     assert_eq!(conf.status, ConfessionStatus::Deleted);
@@ -105,7 +166,7 @@ fn a2_admin_can_delete_any_confession() {
     let (env, client, admin, author) = setup();
     let id = create(&client, &env, &author, 2);
 
-    client.delete_confession(&admin, &id, &3_000_000);
+    client.delete_confession(&admin, &id, &3_000_000, &None);
 
     let conf = client.get_confession(&id);
     assert_eq!(conf.status, ConfessionStatus::Deleted);
@@ -118,7 +179,7 @@ fn a3_unauthorized_delete_is_rejected() {
     let outsider = Address::generate(&env);
     let id = create(&client, &env, &author, 3);
 
-    let result = client.try_delete_confession(&outsider, &id, &2_000_000);
+    let result = client.try_delete_confession(&outsider, &id, &2_000_000, &None);
     assert!(
         result.is_err(),
         "unauthorized delete must return an error, not succeed"
@@ -132,10 +193,10 @@ fn a4_double_delete_is_rejected() {
     let (env, client, _admin, author) = setup();
     let id = create(&client, &env, &author, 4);
 
-    client.delete_confession(&author, &id, &2_000_000);
+    client.delete_confession(&author, &id, &2_000_000, &None);
 
     // Second delete must fail — confession is already in terminal state.
-    let result = client.try_delete_confession(&author, &id, &3_000_000);
+    let result = client.try_delete_confession(&author, &id, &3_000_000, &None);
     assert!(
         result.is_err(),
         "deleting an already-deleted confession must fail"
@@ -147,7 +208,7 @@ fn a4_double_delete_is_rejected() {
 #[should_panic(expected = "confession not found")]
 fn a5_delete_nonexistent_confession_panics() {
     let (_env, client, _admin, author) = setup();
-    client.delete_confession(&author, &9_999, &1_000_000);
+    client.delete_confession(&author, &9_999, &1_000_000, &None);
 }
 
 // ─── Block B – update_status ──────────────────────────────────────────────────
@@ -158,7 +219,7 @@ fn b1_author_can_update_own_confession_status() {
     let (env, client, _admin, author) = setup();
     let id = create(&client, &env, &author, 10);
 
-    client.update_status(&author, &id, &ConfessionStatus::Flagged, &5_000_000);
+    client.update_status(&author, &id, &ConfessionStatus::Flagged, &5_000_000, &None);
 
     let conf = client.get_confession(&id);
     assert_eq!(conf.status, ConfessionStatus::Flagged);
@@ -172,7 +233,7 @@ fn b2_admin_can_update_any_confession_status() {
     let (env, client, admin, author) = setup();
     let id = create(&client, &env, &author, 11);
 
-    client.update_status(&admin, &id, &ConfessionStatus::Flagged, &6_000_000);
+    client.update_status(&admin, &id, &ConfessionStatus::Flagged, &6_000_000, &None);
 
     let conf = client.get_confession(&id);
     assert_eq!(conf.status, ConfessionStatus::Flagged);
@@ -185,7 +246,13 @@ fn b3_unauthorized_update_is_rejected() {
     let outsider = Address::generate(&env);
     let id = create(&client, &env, &author, 12);
 
-    let result = client.try_update_status(&outsider, &id, &ConfessionStatus::Flagged, &5_000_000);
+    let result = client.try_update_status(
+        &outsider,
+        &id,
+        &ConfessionStatus::Flagged,
+        &5_000_000,
+        &None,
+    );
     assert!(result.is_err(), "unauthorized update must return an error");
 }
 
@@ -196,9 +263,10 @@ fn b4_cannot_update_deleted_confession() {
     let (env, client, _admin, author) = setup();
     let id = create(&client, &env, &author, 13);
 
-    client.delete_confession(&author, &id, &2_000_000);
+    client.delete_confession(&author, &id, &2_000_000, &None);
 
-    let result = client.try_update_status(&author, &id, &ConfessionStatus::Active, &3_000_000);
+    let result =
+        client.try_update_status(&author, &id, &ConfessionStatus::Active, &3_000_000, &None);
     assert!(
         result.is_err(),
         "updating a deleted confession must fail — deleted is a terminal state"
@@ -210,7 +278,13 @@ fn b4_cannot_update_deleted_confession() {
 #[should_panic(expected = "confession not found")]
 fn b5_update_nonexistent_confession_panics() {
     let (_env, client, _admin, author) = setup();
-    client.update_status(&author, &9_999, &ConfessionStatus::Flagged, &1_000_000);
+    client.update_status(
+        &author,
+        &9_999,
+        &ConfessionStatus::Flagged,
+        &1_000_000,
+        &None,
+    );
 }
 
 /// B6: author can exercise all non-terminal transitions on their own confession.
@@ -219,10 +293,10 @@ fn b6_author_can_set_all_non_terminal_statuses() {
     let (env, client, _admin, author) = setup();
     let id = create(&client, &env, &author, 14);
 
-    client.update_status(&author, &id, &ConfessionStatus::Flagged, &2_000_000);
+    client.update_status(&author, &id, &ConfessionStatus::Flagged, &2_000_000, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
 
-    client.update_status(&author, &id, &ConfessionStatus::Active, &3_000_000);
+    client.update_status(&author, &id, &ConfessionStatus::Active, &3_000_000, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Active);
 }
 
@@ -232,13 +306,13 @@ fn b7_admin_can_set_all_non_terminal_statuses() {
     let (env, client, admin, author) = setup();
     let id = create(&client, &env, &author, 15);
 
-    client.update_status(&admin, &id, &ConfessionStatus::Flagged, &2_000_000);
+    client.update_status(&admin, &id, &ConfessionStatus::Flagged, &2_000_000, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
 
-    client.update_status(&admin, &id, &ConfessionStatus::Active, &3_000_000);
+    client.update_status(&admin, &id, &ConfessionStatus::Active, &3_000_000, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Active);
 
-    client.update_status(&admin, &id, &ConfessionStatus::Deleted, &4_000_000);
+    client.update_status(&admin, &id, &ConfessionStatus::Deleted, &4_000_000, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Deleted);
 }
 
@@ -250,7 +324,7 @@ fn c1_create_blocked_while_paused() {
     let (env, client, admin, author) = setup();
     pause_contract(&client, &admin);
 
-    let result = client.try_create_confession(&author, &h(&env, 50), &1_000_000);
+    let result = client.try_create_confession(&author, &h(&env, 50), &1_000_000, &None);
     assert!(
         result.is_err(),
         "create must be blocked when contract is paused"
@@ -264,7 +338,7 @@ fn c2_create_succeeds_after_unpause() {
     pause_contract(&client, &admin);
     unpause_contract(&client, &admin);
 
-    let id = client.create_confession(&author, &h(&env, 51), &1_000_000);
+    let id = client.create_confession(&author, &h(&env, 51), &1_000_000, &None);
     assert_eq!(id, 1, "first confession after unpause must get id 1");
 }
 
@@ -280,7 +354,8 @@ fn d1_update_status_blocked_while_paused() {
 
     pause_contract(&client, &admin);
 
-    let result = client.try_update_status(&author, &id, &ConfessionStatus::Flagged, &2_000_000);
+    let result =
+        client.try_update_status(&author, &id, &ConfessionStatus::Flagged, &2_000_000, &None);
     assert!(
         result.is_err(),
         "update_status must be blocked when contract is paused"
@@ -296,7 +371,7 @@ fn d2_update_status_succeeds_after_unpause() {
     pause_contract(&client, &admin);
     unpause_contract(&client, &admin);
 
-    client.update_status(&author, &id, &ConfessionStatus::Flagged, &3_000_000);
+    client.update_status(&author, &id, &ConfessionStatus::Flagged, &3_000_000, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
 }
 
@@ -310,7 +385,7 @@ fn e1_delete_blocked_while_paused() {
 
     pause_contract(&client, &admin);
 
-    let result = client.try_delete_confession(&author, &id, &2_000_000);
+    let result = client.try_delete_confession(&author, &id, &2_000_000, &None);
     assert!(
         result.is_err(),
         "delete_confession must be blocked when contract is paused"
@@ -326,13 +401,13 @@ fn e2_delete_succeeds_after_unpause() {
     pause_contract(&client, &admin);
     unpause_contract(&client, &admin);
 
-    client.delete_confession(&author, &id, &3_000_000);
+    client.delete_confession(&author, &id, &3_000_000, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Deleted);
 }
 
 // ─── Block F – cross-cutting ──────────────────────────────────────────────────
 
-/// F1: read-only methods (get_confession, get_by_hash, get_author_confessions,
+/// F1: read-only methods (get_confession, get_by_hash, list_author_confessions,
 ///     get_total_count) remain accessible while the contract is paused.
 #[test]
 fn f1_reads_are_not_blocked_by_pause() {
@@ -349,7 +424,7 @@ fn f1_reads_are_not_blocked_by_pause() {
     let found = client.get_by_hash(&hash);
     assert_eq!(found, id);
 
-    let ids = client.get_author_confessions(&author);
+    let ids = client.list_author_confessions(&author, &None, &10).items;
     assert_eq!(ids.len(), 1);
 
     let count = client.get_total_count();
@@ -367,14 +442,20 @@ fn f2_author_isolation() {
     let id_b = create(&client, &env, &author_b, 91);
 
     // author_b cannot update author_a's confession
-    let upd = client.try_update_status(&author_b, &id_a, &ConfessionStatus::Flagged, &2_000_000);
+    let upd = client.try_update_status(
+        &author_b,
+        &id_a,
+        &ConfessionStatus::Flagged,
+        &2_000_000,
+        &None,
+    );
     assert!(
         upd.is_err(),
         "author_b must not be able to update author_a's confession"
     );
 
     // author_a cannot delete author_b's confession
-    let del = client.try_delete_confession(&author_a, &id_b, &2_000_000);
+    let del = client.try_delete_confession(&author_a, &id_b, &2_000_000, &None);
     assert!(
         del.is_err(),
         "author_a must not be able to delete author_b's confession"
@@ -397,10 +478,10 @@ fn f3_admin_update_then_delete_sequence() {
     let (env, client, admin, author) = setup();
     let id = create(&client, &env, &author, 92);
 
-    client.update_status(&admin, &id, &ConfessionStatus::Flagged, &2_000_000);
+    client.update_status(&admin, &id, &ConfessionStatus::Flagged, &2_000_000, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
 
-    client.delete_confession(&admin, &id, &3_000_000);
+    client.delete_confession(&admin, &id, &3_000_000, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Deleted);
 }
 
@@ -413,10 +494,10 @@ fn f4_updated_at_is_set_correctly() {
     // updated_at starts at 0
     assert_eq!(client.get_confession(&id).updated_at, 0);
 
-    client.update_status(&author, &id, &ConfessionStatus::Flagged, &5_555_000);
+    client.update_status(&author, &id, &ConfessionStatus::Flagged, &5_555_000, &None);
     assert_eq!(client.get_confession(&id).updated_at, 5_555_000);
 
-    client.delete_confession(&author, &id, &6_666_000);
+    client.delete_confession(&author, &id, &6_666_000, &None);
     assert_eq!(client.get_confession(&id).updated_at, 6_666_000);
 }
 
@@ -429,10 +510,10 @@ fn f5_author_index_unchanged_by_status_changes() {
     let id1 = create(&client, &env, &author, 94);
     let id2 = create(&client, &env, &author, 95);
 
-    client.update_status(&author, &id1, &ConfessionStatus::Flagged, &2_000_000);
-    client.delete_confession(&author, &id2, &3_000_000);
+    client.update_status(&author, &id1, &ConfessionStatus::Flagged, &2_000_000, &None);
+    client.delete_confession(&author, &id2, &3_000_000, &None);
 
-    let ids = client.get_author_confessions(&author);
+    let ids = client.list_author_confessions(&author, &None, &10).items;
     assert_eq!(ids.len(), 2, "author index must still contain both entries");
     assert!(
         ids.iter().any(|x| x == id1),
@@ -452,13 +533,28 @@ fn g1_replay_attempt_with_duplicate_nonce_is_rejected() {
 
     assert_eq!(client.get_expected_nonce(&author), 1);
     assert_eq!(
-        client.update_status_seq(&author, &id, &ConfessionStatus::Flagged, &2_000_000, &1),
+        client.update_status_seq(
+            &author,
+            &id,
+            &ConfessionStatus::Flagged,
+            &2_000_000,
+            &1,
+            &None,
+            &None
+        ),
         ()
     );
     assert_eq!(client.get_expected_nonce(&author), 2);
 
-    let replay =
-        client.try_update_status_seq(&author, &id, &ConfessionStatus::Active, &3_000_000, &1);
+    let replay = client.try_update_status_seq(
+        &author,
+        &id,
+        &ConfessionStatus::Active,
+        &3_000_000,
+        &1,
+        &None,
+        &None,
+    );
     assert_eq!(replay, Err(Ok(ReplayError::InvalidNonce)));
 }
 
@@ -469,11 +565,864 @@ fn g2_stale_nonce_is_rejected_for_delete() {
     let id = create(&client, &env, &author, 101);
 
     assert_eq!(client.get_expected_nonce(&author), 1);
-    client.update_status_seq(&author, &id, &ConfessionStatus::Flagged, &2_000_000, &1);
+    client.update_status_seq(
+        &author,
+        &id,
+        &ConfessionStatus::Flagged,
+        &2_000_000,
+        &1,
+        &None,
+        &None,
+    );
 
-    let stale_delete = client.try_delete_confession_seq(&author, &id, &3_000_000, &1);
+    let stale_delete = client.try_delete_confession_seq(&author, &id, &3_000_000, &1, &None, &None);
     assert_eq!(stale_delete, Err(Ok(ReplayError::InvalidNonce)));
 
-    client.delete_confession_seq(&author, &id, &4_000_000, &2);
+    client.delete_confession_seq(&author, &id, &4_000_000, &2, &None, &None);
     assert_eq!(client.get_confession(&id).status, ConfessionStatus::Deleted);
 }
+
+/// G3: parallel workers may consume nonces out of order; each is accepted once.
+#[test]
+fn g3_out_of_order_nonces_are_accepted_once() {
+    let (env, client, _admin, author) = setup();
+
+    client.create_confession_seq(&author, &h(&env, 110), &1_000, &3, &None, &None);
+    client.create_confession_seq(&author, &h(&env, 111), &1_000, &2, &None, &None);
+
+    let window = client.get_nonce_window(&author);
+    assert_eq!(window.base, 1);
+    assert_eq!(window.used, 0b110);
+
+    let replay = client.try_create_confession_seq(&author, &h(&env, 112), &1_000, &3, &None, &None);
+    assert_eq!(replay, Err(Ok(ReplayError::InvalidNonce)));
+
+    // Filling the gap slides the window past every consumed nonce.
+    client.create_confession_seq(&author, &h(&env, 113), &1_000, &1, &None, &None);
+    let window = client.get_nonce_window(&author);
+    assert_eq!(window.base, 4);
+    assert_eq!(window.used, 0);
+    assert_eq!(client.get_expected_nonce(&author), 4);
+}
+
+/// G4: a nonce past the end of the window is rejected, not silently accepted.
+#[test]
+fn g4_nonce_beyond_window_is_rejected() {
+    let (env, client, _admin, author) = setup();
+
+    let too_far = 1 + NONCE_WINDOW_SIZE;
+    let res =
+        client.try_create_confession_seq(&author, &h(&env, 120), &1_000, &too_far, &None, &None);
+    assert_eq!(res, Err(Ok(ReplayError::NonceTooFarAhead)));
+
+    let last_in_window = NONCE_WINDOW_SIZE;
+    client.create_confession_seq(
+        &author,
+        &h(&env, 121),
+        &1_000,
+        &last_in_window,
+        &None,
+        &None,
+    );
+
+    client.create_confession_seq(&author, &h(&env, 122), &1_000, &1, &None, &None);
+    client.create_confession_seq(&author, &h(&env, 123), &1_000, &too_far, &None, &None);
+}
+
+/// G5: expired requests fail and leave the nonce available.
+#[test]
+fn g5_expired_request_is_rejected() {
+    let (env, client, _admin, author) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 500);
+
+    let res =
+        client.try_create_confession_seq(&author, &h(&env, 130), &1_000, &1, &Some(499), &None);
+    assert_eq!(res, Err(Ok(ReplayError::RequestExpired)));
+    assert_eq!(client.get_expected_nonce(&author), 1);
+
+    client.create_confession_seq(&author, &h(&env, 130), &1_000, &1, &Some(500), &None);
+    assert_eq!(client.get_expected_nonce(&author), 2);
+}
+
+/// G6: callers whose nonce was stored by the pre-window scheme keep their position.
+#[test]
+fn g6_legacy_caller_nonce_seeds_window() {
+    let (env, client, _admin, author) = setup();
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::CallerNonce(author.clone()), &7u64);
+    });
+
+    assert_eq!(client.get_expected_nonce(&author), 7);
+    let stale = client.try_create_confession_seq(&author, &h(&env, 140), &1_000, &6, &None, &None);
+    assert_eq!(stale, Err(Ok(ReplayError::InvalidNonce)));
+
+    client.create_confession_seq(&author, &h(&env, 140), &1_000, &7, &None, &None);
+    assert_eq!(client.get_expected_nonce(&author), 8);
+}
+
+// ─── Block H – scoped pauses ─────────────────────────────────────────────────
+
+fn reason(env: &Env) -> String {
+    String::from_str(env, "spam incident")
+}
+
+/// H1: a create-scope pause blocks only creation.
+#[test]
+fn h1_create_scope_pause_leaves_other_scopes_open() {
+    let (env, client, admin, author) = setup();
+    let id = create(&client, &env, &author, 150);
+
+    client.pause_scope(&admin, &PauseScope::Create, &reason(&env), &3_600);
+
+    assert!(client.is_scope_paused(&PauseScope::Create));
+    assert!(!client.is_scope_paused(&PauseScope::Delete));
+    assert!(client
+        .try_create_confession(&author, &h(&env, 151), &1_000_000, &None)
+        .is_err());
+    client.update_status(&author, &id, &ConfessionStatus::Flagged, &2_000_000, &None);
+    client.delete_confession(&author, &id, &3_000_000, &None);
+
+    client.unpause_scope(&admin, &PauseScope::Create, &reason(&env));
+    create(&client, &env, &author, 151);
+}
+
+/// H2: the global pause applies to every scope.
+#[test]
+fn h2_global_pause_overrides_scopes() {
+    let (env, client, admin, author) = setup();
+    let id = create(&client, &env, &author, 152);
+    pause_contract(&client, &admin);
+
+    assert!(client.is_scope_paused(&PauseScope::Delete));
+    assert!(client
+        .try_delete_confession(&author, &id, &2_000_000, &None)
+        .is_err());
+}
+
+/// H3: lifting a scope pause leaves an active global pause in place.
+#[test]
+fn h3_unpause_scope_keeps_global_pause() {
+    let (env, client, admin, author) = setup();
+    client.pause_scope(&admin, &PauseScope::Create, &reason(&env), &3_600);
+    pause_contract(&client, &admin);
+
+    client.unpause_scope(&admin, &PauseScope::Create, &reason(&env));
+
+    assert!(client
+        .try_create_confession(&author, &h(&env, 153), &1_000_000, &None)
+        .is_err());
+    unpause_contract(&client, &admin);
+    create(&client, &env, &author, 153);
+}
+
+/// H4: a scope cannot be paused twice or unpaused while open.
+#[test]
+fn h4_double_scope_transitions_are_rejected() {
+    let (env, client, admin, _author) = setup();
+
+    assert!(client
+        .try_unpause_scope(&admin, &PauseScope::Update, &reason(&env))
+        .is_err());
+    client.pause_scope(&admin, &PauseScope::Update, &reason(&env), &3_600);
+    assert!(client
+        .try_pause_scope(&admin, &PauseScope::Update, &reason(&env), &3_600)
+        .is_err());
+}
+
+/// H5: authors and other outsiders cannot pause scopes.
+#[test]
+fn h5_scope_pause_requires_admin() {
+    let (env, client, _admin, author) = setup();
+
+    assert!(client
+        .try_pause_scope(&author, &PauseScope::Create, &reason(&env), &3_600)
+        .is_err());
+    assert!(!client.is_scope_paused(&PauseScope::Create));
+}
+
+// ─── Block I – guardians and expiring pauses ─────────────────────────────────
+
+/// I1: guardians hold the brake but not the release.
+#[test]
+fn i1_guardian_can_pause_but_not_unpause() {
+    let (env, client, admin, _author) = setup();
+    let guardian = Address::generate(&env);
+    client.grant_guardian(&admin, &guardian);
+
+    client.pause(&guardian, &reason(&env), &3_600);
+    assert!(client.get_pause_state().paused);
+    assert!(client.try_unpause(&guardian, &reason(&env)).is_err());
+
+    client.unpause(&admin, &reason(&env));
+    assert!(!client.get_pause_state().paused);
+}
+
+/// I2: writes resume without intervention once the pause expires.
+#[test]
+fn i2_pause_lapses_after_duration() {
+    let (env, client, admin, author) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.pause(&admin, &reason(&env), &600);
+
+    let state = client.get_pause_state();
+    assert!(state.paused);
+    assert_eq!(state.expires_at, Some(1_600));
+    assert!(client
+        .try_create_confession(&author, &h(&env, 160), &1_000_000, &None)
+        .is_err());
+
+    env.ledger().with_mut(|l| l.timestamp = 1_600);
+    assert!(!client.get_pause_state().paused);
+    assert_eq!(client.get_pause_state().expires_at, None);
+    create(&client, &env, &author, 160);
+}
+
+/// I3: pauses must lapse within the bound.
+#[test]
+fn i3_invalid_pause_durations_are_rejected() {
+    let (env, client, admin, _author) = setup();
+
+    assert!(client.try_pause(&admin, &reason(&env), &0).is_err());
+    assert!(client
+        .try_pause(&admin, &reason(&env), &(MAX_PAUSE_DURATION_SECONDS + 1))
+        .is_err());
+    assert!(client
+        .try_pause_scope(
+            &admin,
+            &PauseScope::Create,
+            &reason(&env),
+            &(MAX_PAUSE_DURATION_SECONDS + 1)
+        )
+        .is_err());
+    client.pause(&admin, &reason(&env), &MAX_PAUSE_DURATION_SECONDS);
+}
+
+/// I4: a quorum-approved pause lapses once its duration, capped at
+/// `MAX_PAUSE_DURATION_SECONDS`, has passed.
+#[test]
+fn i4_governance_pause_lapses_after_the_cap() {
+    let (env, client, admin, author) = setup();
+    pause_contract(&client, &admin);
+    let state = client.get_pause_state();
+    assert_eq!(state.expires_at, Some(MAX_PAUSE_DURATION_SECONDS));
+    assert!(state.governed);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = MAX_PAUSE_DURATION_SECONDS - 1);
+    assert!(client
+        .try_create_confession(&author, &h(&env, 161), &1_000_000, &None)
+        .is_err());
+    env.ledger()
+        .with_mut(|l| l.timestamp = MAX_PAUSE_DURATION_SECONDS);
+    assert!(!client.get_pause_state().paused);
+    create(&client, &env, &author, 161);
+
+    let id = client.gov_propose(
+        &admin,
+        &crate::governance::model::CriticalAction::PauseFor(600),
+    );
+    client.gov_approve(&admin, &id);
+    client.gov_execute(&admin, &id);
+    let until = MAX_PAUSE_DURATION_SECONDS + 600;
+    assert_eq!(client.get_pause_state().expires_at, Some(until));
+
+    env.ledger().with_mut(|l| l.timestamp = until);
+    create(&client, &env, &author, 162);
+
+    for duration in [0, MAX_PAUSE_DURATION_SECONDS + 1] {
+        let id = client.gov_propose(
+            &admin,
+            &crate::governance::model::CriticalAction::PauseFor(duration),
+        );
+        client.gov_approve(&admin, &id);
+        assert!(client.try_gov_execute(&admin, &id).is_err());
+    }
+    assert!(!client.get_pause_state().paused);
+}
+
+/// I5: every pause action is kept in order for audit.
+#[test]
+fn i5_pause_history_is_recorded() {
+    let (env, client, admin, _author) = setup();
+    let guardian = Address::generate(&env);
+    client.grant_guardian(&admin, &guardian);
+    env.ledger().with_mut(|l| l.timestamp = 100);
+
+    client.pause_scope(&guardian, &PauseScope::Create, &reason(&env), &60);
+    client.pause(&admin, &reason(&env), &120);
+    client.unpause(&admin, &reason(&env));
+    pause_contract(&client, &admin);
+
+    unpause_contract(&client, &admin);
+
+    let first = client.pause_history(&None, &2);
+    assert_eq!(first.items.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+    let scoped = first.items.get(0).unwrap();
+    assert_eq!(scoped.target, PauseTarget::Scope(PauseScope::Create));
+    assert_eq!(scoped.action, PauseAction::Pause);
+    assert_eq!(scoped.origin, PauseOrigin::Direct);
+    assert_eq!(scoped.actor, guardian);
+    assert_eq!(scoped.reason, reason(&env));
+    assert_eq!((scoped.at, scoped.expires_at), (100, Some(160)));
+    assert_eq!(first.items.get(1).unwrap().target, PauseTarget::Global);
+    assert_eq!(first.items.get(1).unwrap().expires_at, Some(220));
+
+    let rest = client.pause_history(&first.next_cursor, &5);
+    assert_eq!(rest.items.len(), 3);
+    assert_eq!(rest.next_cursor, None);
+    let lifted = rest.items.get(0).unwrap();
+    assert_eq!(
+        (lifted.target, lifted.action, lifted.origin),
+        (
+            PauseTarget::Global,
+            PauseAction::Unpause,
+            PauseOrigin::Direct
+        )
+    );
+    assert_eq!(lifted.expires_at, None);
+    let governed = rest.items.get(1).unwrap();
+    assert_eq!(governed.actor, admin);
+    assert_eq!(
+        (governed.action, governed.origin, governed.expires_at),
+        (
+            PauseAction::Pause,
+            PauseOrigin::Governance,
+            Some(100 + MAX_PAUSE_DURATION_SECONDS)
+        )
+    );
+    let released = rest.items.get(2).unwrap();
+    assert_eq!(
+        (released.action, released.origin),
+        (PauseAction::Unpause, PauseOrigin::Governance)
+    );
+}
+
+/// I6: the admin cannot lift a pause that governance put in place, but
+/// governance can lift a direct pause.
+#[test]
+fn i6_only_governance_lifts_a_governance_pause() {
+    let (env, client, admin, author) = setup();
+    pause_contract(&client, &admin);
+
+    assert!(client.try_unpause(&admin, &reason(&env)).is_err());
+    assert!(client.get_pause_state().paused);
+    assert!(client.try_pause(&admin, &reason(&env), &60).is_err());
+
+    unpause_contract(&client, &admin);
+    create(&client, &env, &author, 162);
+
+    client.pause(&admin, &reason(&env), &600);
+    assert!(!client.get_pause_state().governed);
+    unpause_contract(&client, &admin);
+    assert!(!client.get_pause_state().paused);
+}
+
+// ─── Block J – circuit breaker ───────────────────────────────────────────────
+
+fn breaker(max_writes: u32) -> BreakerConfig {
+    BreakerConfig {
+        max_writes_per_window: max_writes,
+        window_ledgers: 10,
+        trip_duration_seconds: 600,
+    }
+}
+
+/// J1: the write that crosses the threshold lands, then creates stop while
+/// moderation keeps working.
+#[test]
+fn j1_confession_spike_pauses_create_scope() {
+    let (env, client, admin, author) = setup();
+    client.configure_circuit_breaker(&admin, &breaker(2));
+
+    let first = create(&client, &env, &author, 170);
+    create(&client, &env, &author, 171);
+    create(&client, &env, &author, 172);
+
+    assert!(client.is_scope_paused(&PauseScope::Create));
+    assert!(!client.get_pause_state().paused);
+    assert!(client
+        .try_create_confession(&author, &h(&env, 173), &1_000_000, &None)
+        .is_err());
+    client.delete_confession(&admin, &first, &2_000_000, &None);
+
+    let record = client.pause_history(&None, &10).items.get(0).unwrap();
+    assert_eq!(record.target, PauseTarget::Scope(PauseScope::Create));
+    assert_eq!(record.actor, client.address);
+}
+
+/// J2: no one has to unpause after a trip.
+#[test]
+fn j2_breaker_pause_lapses() {
+    let (env, client, admin, author) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.configure_circuit_breaker(&admin, &breaker(1));
+
+    create(&client, &env, &author, 174);
+    create(&client, &env, &author, 175);
+    assert!(client.is_scope_paused(&PauseScope::Create));
+
+    env.ledger().with_mut(|l| l.timestamp = 1_600);
+    assert!(!client.is_scope_paused(&PauseScope::Create));
+    create(&client, &env, &author, 176);
+}
+
+/// J3: thresholds are an admin setting and must be coherent.
+#[test]
+fn j3_breaker_config_is_admin_only_and_validated() {
+    let (_env, client, admin, author) = setup();
+
+    assert!(client
+        .try_configure_circuit_breaker(&author, &breaker(5))
+        .is_err());
+    let mut unbounded = breaker(5);
+    unbounded.trip_duration_seconds = MAX_PAUSE_DURATION_SECONDS + 1;
+    assert!(client
+        .try_configure_circuit_breaker(&admin, &unbounded)
+        .is_err());
+
+    client.configure_circuit_breaker(&admin, &breaker(5));
+    assert_eq!(client.get_circuit_breaker_config(), breaker(5));
+    client.configure_circuit_breaker(&admin, &BreakerConfig::default());
+}
+
+// ─── Block K – author suspensions ────────────────────────────────────────────
+
+/// K1: the suspension is enforced on the create paths for that author only.
+#[test]
+fn k1_suspended_author_cannot_create() {
+    let (env, client, admin, author) = setup();
+    let other = Address::generate(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    client.suspend_author(&admin, &author, &2_000, &7);
+
+    assert!(client
+        .try_create_confession(&author, &h(&env, 180), &1_000_000, &None)
+        .is_err());
+    assert!(client
+        .try_create_ephemeral_confession(&author, &h(&env, 180), &1_000_000, &5_000)
+        .is_err());
+    create(&client, &env, &other, 181);
+
+    let suspension = client.get_suspension(&author).unwrap();
+    assert_eq!(suspension.moderator, admin);
+    assert_eq!(
+        (
+            suspension.suspended_at,
+            suspension.until,
+            suspension.reason_code
+        ),
+        (1_000, 2_000, 7)
+    );
+}
+
+/// K2: no one has to remember to lift a suspension.
+#[test]
+fn k2_suspension_lapses() {
+    let (env, client, admin, author) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.suspend_author(&admin, &author, &2_000, &1);
+
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+    assert_eq!(client.get_suspension(&author), None);
+    create(&client, &env, &author, 182);
+}
+
+/// K3: moderators can end a suspension early.
+#[test]
+fn k3_lift_suspension_restores_creation() {
+    let (env, client, admin, author) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.suspend_author(&admin, &author, &2_000, &1);
+
+    client.lift_suspension(&admin, &author);
+    assert_eq!(client.get_suspension(&author), None);
+    create(&client, &env, &author, 183);
+    assert!(client.try_lift_suspension(&admin, &author).is_err());
+}
+
+/// K4: suspending is a moderator power that cannot be turned on moderators.
+#[test]
+fn k4_only_moderators_can_suspend() {
+    let (env, client, admin, author) = setup();
+    let operator = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.grant_operator(&admin, &operator);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    assert!(client
+        .try_suspend_author(&other, &author, &2_000, &1)
+        .is_err());
+    assert!(client
+        .try_suspend_author(&operator, &admin, &2_000, &1)
+        .is_err());
+    assert!(client
+        .try_suspend_author(&operator, &author, &1_000, &1)
+        .is_err());
+
+    client.suspend_author(&operator, &author, &2_000, &1);
+    assert!(client.try_lift_suspension(&other, &author).is_err());
+    client.lift_suspension(&operator, &author);
+}
+
+/// K5: a suspended relayer account cannot submit pseudonymous confessions.
+#[test]
+fn k5_suspended_submitter_cannot_commit() {
+    let (env, client, admin, author) = setup();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.suspend_author(&admin, &author, &2_000, &1);
+
+    assert!(client
+        .try_create_confession_committed(&author, &h(&env, 184), &h(&env, 185), &1_000_000)
+        .is_err());
+}
+
+/// K6: a suspended author can post through another submitter, since only the
+///     commitment is known, but cannot attach the confession to themselves
+///     until the suspension ends.
+#[test]
+fn k6_suspended_author_cannot_prove_authorship() {
+    let (env, client, admin, author) = setup();
+    let submitter = Address::generate(&env);
+    let salt = h(&env, 186);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.suspend_author(&admin, &author, &2_000, &1);
+
+    let id = client.create_confession_committed(
+        &submitter,
+        &compute_author_commitment(&env, &author, &salt),
+        &h(&env, 187),
+        &1_000_000,
+    );
+    assert!(client.try_prove_authorship(&author, &id, &salt).is_err());
+    assert_eq!(client.get_confession(&id).author, None);
+
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+    client.prove_authorship(&author, &id, &salt);
+    assert_eq!(client.get_confession(&id).author, Some(author));
+}
+
+// ─── Block L – author rotation ───────────────────────────────────────────────
+
+fn author_ids(client: &ConfessionRegistryClient, author: &Address) -> Vec<u64> {
+    client.list_author_confessions(author, &None, &50).items
+}
+
+/// L1: the new key gains full control and the old one loses it.
+#[test]
+fn l1_rotate_author_moves_confessions() {
+    let (env, client, _admin, old) = setup();
+    let new = Address::generate(&env);
+    let first = create(&client, &env, &old, 190);
+    let second = create(&client, &env, &old, 191);
+
+    client.rotate_author(&old, &new);
+
+    assert_eq!(author_ids(&client, &new), vec![&env, first, second]);
+    assert!(author_ids(&client, &old).is_empty());
+    assert_eq!(client.get_author_confession_count(&old), 0);
+    assert_eq!(client.get_confession(&first).author, Some(new.clone()));
+    assert!(client
+        .try_delete_confession(&old, &first, &2_000_000, &None)
+        .is_err());
+    client.delete_confession(&new, &first, &2_000_000, &None);
+}
+
+/// L2: only live confessions move, appended after the new key's own.
+#[test]
+fn l2_rotation_skips_erased_and_appends() {
+    let (env, client, _admin, old) = setup();
+    let new = Address::generate(&env);
+    let own = create(&client, &env, &new, 192);
+    let erased = create(&client, &env, &old, 193);
+    let kept = create(&client, &env, &old, 194);
+    client.erase_confession(&old, &erased);
+
+    client.rotate_author(&old, &new);
+
+    assert_eq!(author_ids(&client, &new), vec![&env, own, kept]);
+    assert_eq!(client.get_author_confession_count(&new), 2);
+    client.erase_confession(&new, &kept);
+    assert_eq!(author_ids(&client, &new), vec![&env, own]);
+}
+
+/// L3: losing the old key is recoverable with admin approval, once the old
+///     key has had time to object.
+#[test]
+fn l3_admin_approved_recovery_waits_for_delay() {
+    let (env, client, admin, old) = setup();
+    let new = Address::generate(&env);
+    let operator = Address::generate(&env);
+    client.grant_operator(&admin, &operator);
+    let id = create(&client, &env, &old, 195);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    assert!(client.try_recover_author(&operator, &old, &new).is_err());
+    client.recover_author(&admin, &old, &new);
+    assert!(client.try_recover_author(&admin, &old, &new).is_err());
+    let pending = client.get_pending_recovery(&old).unwrap();
+    assert_eq!(
+        (pending.approved_by, pending.executable_at),
+        (admin.clone(), 1_000 + AUTHOR_RECOVERY_DELAY_SECONDS)
+    );
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = 1_000 + AUTHOR_RECOVERY_DELAY_SECONDS - 1);
+    assert!(client.try_execute_recovery(&old).is_err());
+    assert_eq!(client.get_confession(&id).author, Some(old.clone()));
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = 1_000 + AUTHOR_RECOVERY_DELAY_SECONDS);
+    assert!(client.execute_recovery(&old));
+
+    assert_eq!(client.get_confession(&id).author, Some(new.clone()));
+    assert_eq!(author_ids(&client, &new), vec![&env, id]);
+    assert_eq!(client.get_pending_recovery(&old), None);
+}
+
+/// L4: rotation cannot be used as a no-op, to shed a suspension or to move
+///     confessions onto a suspended key.
+#[test]
+fn l4_invalid_rotations_are_rejected() {
+    let (env, client, admin, old) = setup();
+    let new = Address::generate(&env);
+    create(&client, &env, &old, 196);
+
+    assert!(client.try_rotate_author(&old, &old).is_err());
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.suspend_author(&admin, &new, &2_000, &1);
+    assert!(client.try_rotate_author(&old, &new).is_err());
+    client.recover_author(&admin, &old, &new);
+    env.ledger()
+        .with_mut(|l| l.timestamp = 1_000 + AUTHOR_RECOVERY_DELAY_SECONDS);
+    client.suspend_author(&admin, &new, &(2_000 + AUTHOR_RECOVERY_DELAY_SECONDS), &1);
+    assert!(client.try_execute_recovery(&old).is_err());
+    client.lift_suspension(&admin, &new);
+
+    client.suspend_author(&admin, &old, &(2_000 + AUTHOR_RECOVERY_DELAY_SECONDS), &1);
+    assert!(client.try_rotate_author(&old, &new).is_err());
+    assert_eq!(author_ids(&client, &old).len(), 1);
+}
+
+/// L5: a stolen admin approval cannot take over an author who still holds
+///     the old key.
+#[test]
+fn l5_old_key_cancels_pending_recovery() {
+    let (env, client, admin, old) = setup();
+    let new = Address::generate(&env);
+    let id = create(&client, &env, &old, 197);
+
+    assert!(client.try_cancel_recovery(&old).is_err());
+    client.recover_author(&admin, &old, &new);
+    client.cancel_recovery(&old);
+    assert_eq!(client.get_pending_recovery(&old), None);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = AUTHOR_RECOVERY_DELAY_SECONDS);
+    assert!(client.try_execute_recovery(&old).is_err());
+    assert_eq!(client.get_confession(&id).author, Some(old.clone()));
+}
+
+/// 32-byte hash unique per `n`, for tests that need more than 256 of them.
+fn wide_hash(env: &Env, n: u32) -> BytesN<32> {
+    let mut bytes = [0xAB; 32];
+    bytes[..4].copy_from_slice(&n.to_be_bytes());
+    BytesN::from_array(env, &bytes)
+}
+
+/// L6: an index too long for one call moves in batches; the old key cannot
+///     append while the rotation is unfinished.
+#[test]
+fn l6_long_index_rotates_across_calls() {
+    let (env, client, admin, old) = setup();
+    let new = Address::generate(&env);
+    client.configure_author_rate_limit(&admin, &1_000, &86_400);
+    let total = MAX_ROTATION_INDEX_LEN as u32 + 10;
+    for n in 0..total {
+        client.create_confession(&old, &wide_hash(&env, n), &1_000_000, &None);
+    }
+
+    assert!(!client.rotate_author(&old, &new));
+    let rotation = client.get_rotation(&old).unwrap();
+    assert_eq!(rotation.next_slot, MAX_ROTATION_INDEX_LEN);
+    assert_eq!(
+        client.get_author_confession_count(&new),
+        MAX_ROTATION_INDEX_LEN
+    );
+    assert!(client
+        .try_create_confession(&old, &wide_hash(&env, total), &1_000_000, &None)
+        .is_err());
+    assert!(client.try_rotate_author(&new, &old).is_err());
+
+    assert!(client.continue_rotation(&old));
+    assert_eq!(client.get_rotation(&old), None);
+    assert_eq!(client.get_author_confession_count(&new), total as u64);
+    assert_eq!(client.get_author_confession_count(&old), 0);
+    assert_eq!(client.get_confession(&(total as u64)).author, Some(new));
+    assert!(client.try_continue_rotation(&old).is_err());
+    client.create_confession(&old, &wide_hash(&env, total), &1_000_000, &None);
+}
+
+/// L7: a confession scheduled by the old key is published under the new one.
+#[test]
+fn l7_scheduled_confessions_follow_rotation() {
+    let (env, client, _admin, old) = setup();
+    let new = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let commitment = crate::compute_schedule_commitment(&env, &h(&env, 198), &salt);
+    let schedule_id = client.schedule_confession(&old, &commitment, &5_000);
+
+    client.rotate_author(&old, &new);
+    assert_eq!(client.get_scheduled(&schedule_id).unwrap().author, new);
+
+    env.ledger().with_mut(|l| l.timestamp = 5_000);
+    let id = client.publish_scheduled(&schedule_id, &h(&env, 198), &salt);
+    assert_eq!(client.get_confession(&id).author, Some(new.clone()));
+    assert_eq!(author_ids(&client, &new), vec![&env, id]);
+}
+
+/// L8: merging two keys' schedules stays within
+///     `MAX_PENDING_SCHEDULES_PER_AUTHOR`.
+#[test]
+fn l8_rotation_respects_the_schedule_cap() {
+    let (env, client, _admin, old) = setup();
+    let new = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[8; 32]);
+    let schedule = |author: &Address, seed: u32| {
+        let commitment = crate::compute_schedule_commitment(&env, &wide_hash(&env, seed), &salt);
+        client.schedule_confession(author, &commitment, &5_000)
+    };
+    for seed in 0..MAX_PENDING_SCHEDULES_PER_AUTHOR / 2 {
+        schedule(&old, seed);
+    }
+    let first = schedule(&new, 100);
+    for seed in 101..101 + MAX_PENDING_SCHEDULES_PER_AUTHOR / 2 {
+        schedule(&new, seed);
+    }
+
+    assert!(client.try_rotate_author(&old, &new).is_err());
+    assert_eq!(client.get_rotation(&old), None);
+
+    env.ledger().with_mut(|l| l.timestamp = 5_000);
+    client.publish_scheduled(&first, &wide_hash(&env, 100), &salt);
+    assert!(client.rotate_author(&old, &new));
+    assert!(client
+        .try_schedule_confession(
+            &new,
+            &crate::compute_schedule_commitment(&env, &h(&env, 1), &salt),
+            &6_000
+        )
+        .is_err());
+}
+
+// ─── Block M – content labels ────────────────────────────────────────────────
+
+fn label(env: &Env, name: &str) -> Symbol {
+    Symbol::new(env, name)
+}
+
+/// Admin-defined vocabulary plus an operator to apply it.
+fn setup_labels() -> (
+    Env,
+    ConfessionRegistryClient<'static>,
+    Address,
+    Address,
+    Address,
+) {
+    let (env, client, admin, author) = setup();
+    let operator = Address::generate(&env);
+    client.grant_operator(&admin, &operator);
+    for name in ["nsfw", "self_harm", "spoiler"] {
+        client.add_vocabulary_label(&admin, &label(&env, name));
+    }
+    (env, client, admin, author, operator)
+}
+
+/// M1: every client reads the same labels from the record itself.
+#[test]
+fn m1_operator_labels_show_in_reads() {
+    let (env, client, _admin, author, operator) = setup_labels();
+    let id = create(&client, &env, &author, 200);
+
+    client.add_label(&operator, &id, &label(&env, "nsfw"));
+    client.add_label(&operator, &id, &label(&env, "spoiler"));
+    client.remove_label(&operator, &id, &label(&env, "nsfw"));
+
+    let expected = vec![&env, label(&env, "spoiler")];
+    assert_eq!(client.get_confession(&id).labels, expected);
+    let page = client.list_confessions(&None, &10);
+    assert_eq!(page.items.get(0).unwrap().labels, expected);
+}
+
+/// M2: labels are bounded by the vocabulary and per-confession limit.
+#[test]
+fn m2_labels_are_validated() {
+    let (env, client, admin, author, operator) = setup_labels();
+    let id = create(&client, &env, &author, 201);
+
+    assert!(client
+        .try_add_label(&operator, &id, &label(&env, "unknown"))
+        .is_err());
+    client.add_label(&operator, &id, &label(&env, "nsfw"));
+    assert!(client
+        .try_add_label(&operator, &id, &label(&env, "nsfw"))
+        .is_err());
+    assert!(client
+        .try_remove_label(&operator, &id, &label(&env, "spoiler"))
+        .is_err());
+
+    let extra = ["a", "b", "c", "d", "e", "f", "g", "h"];
+    for (i, name) in extra.iter().enumerate() {
+        let name = label(&env, name);
+        client.add_vocabulary_label(&admin, &name);
+        if (i as u32) + 1 < MAX_LABELS_PER_CONFESSION {
+            client.add_label(&operator, &id, &name);
+        } else {
+            assert!(client.try_add_label(&operator, &id, &name).is_err());
+        }
+    }
+
+    client.delete_confession(&operator, &id, &2_000_000, &None);
+    assert!(client
+        .try_remove_label(&operator, &id, &label(&env, "nsfw"))
+        .is_err());
+}
+
+/// M3: labelling is a moderator power; the vocabulary is an admin one.
+#[test]
+fn m3_label_permissions() {
+    let (env, client, admin, author, operator) = setup_labels();
+    let id = create(&client, &env, &author, 202);
+
+    assert!(client
+        .try_add_label(&author, &id, &label(&env, "nsfw"))
+        .is_err());
+    assert!(client
+        .try_add_vocabulary_label(&operator, &label(&env, "gore"))
+        .is_err());
+
+    client.add_label(&operator, &id, &label(&env, "nsfw"));
+    client.remove_vocabulary_label(&admin, &label(&env, "nsfw"));
+    assert_eq!(client.get_label_vocabulary().len(), 2);
+    // Retiring a label leaves it on existing confessions until removed.
+    assert_eq!(client.get_confession(&id).labels.len(), 1);
+    client.remove_label(&operator, &id, &label(&env, "nsfw"));
+}
+
+/// M4: label changes share the confession's event nonce stream.
+#[test]
+fn m4_label_changes_bump_event_nonce() {
+    let (env, client, _admin, author, operator) = setup_labels();
+    let id = create(&client, &env, &author, 203);
+    let before = client.get_checkpoint().counters.events_emitted;
+
+    client.add_label(&operator, &id, &label(&env, "nsfw"));
+    client.remove_label(&operator, &id, &label(&env, "nsfw"));
+
+    assert_eq!(client.get_checkpoint().counters.events_emitted, before + 2);
+    assert_eq!(client.get_checkpoint().latest_confession_nonce, 3);
+}
@@ -6,15 +6,49 @@
 mod confession_reg_auth;
 
 use soroban_sdk::{
-    contract, contracterror, contractevent, contractimpl, contracttype, Address, BytesN, Env,
-    Symbol, Vec,
+    contract, contracterror, contractevent, contractimpl, contracttype, vec,
+    xdr::{FromXdr, ToXdr},
+    Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
-pub const MAX_AUTHOR_CONFESSIONS_PER_AUTHOR: u32 = 128;
-pub const REGISTRY_PAYLOAD_TOO_LONG: &str = "registry payload too long";
+/// Number of confession IDs stored per persistent author-index bucket.
+pub const AUTHOR_INDEX_BUCKET_SIZE: u32 = 64;
+/// Most author index slots one rotation call moves; longer indexes are
+/// finished with `continue_rotation`. Must be a multiple of
+/// `AUTHOR_INDEX_BUCKET_SIZE`.
+pub const MAX_ROTATION_INDEX_LEN: u64 = AUTHOR_INDEX_BUCKET_SIZE as u64;
+/// Time the old key has to cancel an admin-approved recovery (7 days).
+pub const AUTHOR_RECOVERY_DELAY_SECONDS: u64 = 7 * 86_400;
+/// Maximum number of unpublished scheduled confessions per author.
+pub const MAX_PENDING_SCHEDULES_PER_AUTHOR: u32 = 16;
+/// Default number of confessions an author may create per rate window.
+pub const DEFAULT_MAX_CONFESSIONS_PER_WINDOW: u32 = 128;
+/// Default length of the per-author rate window, in seconds.
+pub const DEFAULT_AUTHOR_RATE_WINDOW_SECONDS: u64 = 86_400;
+/// Maximum page size for `list_author_confessions`.
+pub const MAX_AUTHOR_PAGE_LIMIT: u32 = 50;
+pub const REGISTRY_RATE_LIMITED: &str = "author rate limit exceeded";
+/// Number of nonces above the lowest unused one that may be consumed out of order.
+pub const NONCE_WINDOW_SIZE: u64 = 64;
+/// Maximum number of IDs examined by one `purge_expired` call.
+pub const MAX_PURGE_LIMIT: u32 = 50;
+/// Ledgers an expired ephemeral confession stays stored before its TTL
+/// lapses (30 days at 5 s per ledger).
+pub const EPHEMERAL_PURGE_GRACE_LEDGERS: u32 = 30 * 17_280;
+/// Approximate ledger close time, used to turn expiry times into TTLs.
+const LEDGER_SECONDS: u64 = 5;
+/// Maximum number of IDs examined by one `migrate_confessions` call.
+pub const MAX_MIGRATE_LIMIT: u32 = 50;
+/// Number of fields in a stored `LegacyConfession`.
+const LEGACY_CONFESSION_FIELDS: u32 = 6;
+/// Maximum number of content labels on one confession.
+pub const MAX_LABELS_PER_CONFESSION: u32 = 8;
+/// Maximum size of the admin-managed label vocabulary.
+pub const MAX_LABEL_VOCABULARY: u32 = 32;
 
 #[path = "../../access_control.rs"]
 mod access_control;
+pub mod checkpoint;
 #[path = "../../emergency_pause/mod.rs"]
 mod emergency_pause;
 #[path = "../../error.rs"]
@@ -23,6 +57,8 @@ mod error;
 pub mod events;
 #[path = "../../governance/mod.rs"]
 mod governance;
+
+pub use emergency_pause::{BreakerConfig, BreakerMetric, PauseScope};
 // mod confession_reg_auth;
 
 // ─── Data Types ───
@@ -34,6 +70,8 @@ pub enum ConfessionStatus {
     Active,
     Deleted,
     Flagged,
+    /// Reported by read APIs once `expires_at` has passed; never stored.
+    Expired,
 }
 
 /// On-chain confession record.
@@ -42,8 +80,11 @@ pub enum ConfessionStatus {
 pub struct Confession {
     /// Auto-incrementing confession ID.
     pub id: u64,
-    /// Address of the confession author.
-    pub author: Address,
+    /// Address of the confession author. `None` for pseudonymous confessions
+    /// until the author reveals themselves with `prove_authorship`.
+    pub author: Option<Address>,
+    /// `sha256(author_xdr || salt)` for pseudonymous confessions, `None` otherwise.
+    pub author_commitment: Option<BytesN<32>>,
     /// 32-byte hash of the confession content.
     pub content_hash: BytesN<32>,
     /// Timestamp when the confession was created (ms since epoch).
@@ -52,6 +93,25 @@ pub struct Confession {
     pub updated_at: u64,
     /// Current status of the confession.
     pub status: ConfessionStatus,
+    /// Ledger timestamp (seconds) after which an ephemeral confession expires.
+    pub expires_at: Option<u64>,
+    /// Moderator-applied content labels (e.g. `nsfw`, `spoiler`), drawn from
+    /// the label vocabulary.
+    pub labels: Vec<Symbol>,
+}
+
+/// Confession record layout written before 1.0.0, still found in storage on
+/// upgraded deployments. Read through `load_confession`; rewritten in the
+/// current layout by any update or by `migrate_confessions`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyConfession {
+    pub id: u64,
+    pub author: Address,
+    pub content_hash: BytesN<32>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub status: ConfessionStatus,
 }
 
 #[contractevent(topics = ["confession_created"], data_format = "vec")]
@@ -67,6 +127,52 @@ pub struct ConfessionCreatedEvent {
     pub correlation_id: Option<Symbol>,
 }
 
+#[contractevent(topics = ["confession_committed"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfessionCommittedEvent {
+    #[topic]
+    pub id: u64,
+    pub event_version: u32,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub author_commitment: BytesN<32>,
+    pub content_hash: BytesN<32>,
+    pub correlation_id: Option<Symbol>,
+}
+
+#[contractevent(topics = ["authorship_proven"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorshipProvenEvent {
+    #[topic]
+    pub id: u64,
+    pub event_version: u32,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub author: Address,
+}
+
+#[contractevent(topics = ["confession_purged"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfessionPurgedEvent {
+    #[topic]
+    pub id: u64,
+    pub event_version: u32,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub expires_at: u64,
+    pub content_hash: BytesN<32>,
+}
+
+#[contractevent(topics = ["confession_erased"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfessionErasedEvent {
+    #[topic]
+    pub id: u64,
+    pub event_version: u32,
+    pub nonce: u64,
+    pub timestamp: u64,
+}
+
 #[contractevent(topics = ["confession_updated"], data_format = "vec")]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ConfessionUpdatedEvent {
@@ -92,6 +198,111 @@ pub struct ConfessionDeletedEvent {
     pub correlation_id: Option<Symbol>,
 }
 
+#[contractevent(topics = ["label_added"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LabelAddedEvent {
+    #[topic]
+    pub id: u64,
+    pub event_version: u32,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub moderator: Address,
+    pub label: Symbol,
+}
+
+#[contractevent(topics = ["label_removed"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LabelRemovedEvent {
+    #[topic]
+    pub id: u64,
+    pub event_version: u32,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub moderator: Address,
+    pub label: Symbol,
+}
+
+#[contractevent(topics = ["author_suspended"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorSuspendedEvent {
+    #[topic]
+    pub author: Address,
+    pub event_version: u32,
+    pub timestamp: u64,
+    pub moderator: Address,
+    pub until: u64,
+    pub reason_code: u32,
+}
+
+#[contractevent(topics = ["suspension_lifted"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SuspensionLiftedEvent {
+    #[topic]
+    pub author: Address,
+    pub event_version: u32,
+    pub timestamp: u64,
+    pub moderator: Address,
+}
+
+#[contractevent(topics = ["author_rotated"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorRotatedEvent {
+    #[topic]
+    pub old_author: Address,
+    #[topic]
+    pub new_author: Address,
+    pub event_version: u32,
+    pub timestamp: u64,
+    /// Number of confessions moved to `new_author`.
+    pub moved: u32,
+    /// Owner or admin who approved a recovery; `None` when both keys signed.
+    pub recovered_by: Option<Address>,
+}
+
+#[contractevent(topics = ["recovery_requested"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRequestedEvent {
+    #[topic]
+    pub old_author: Address,
+    #[topic]
+    pub new_author: Address,
+    pub event_version: u32,
+    pub timestamp: u64,
+    pub approved_by: Address,
+    pub executable_at: u64,
+}
+
+#[contractevent(topics = ["recovery_cancelled"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryCancelledEvent {
+    #[topic]
+    pub old_author: Address,
+    pub event_version: u32,
+    pub timestamp: u64,
+}
+
+#[contractevent(topics = ["confession_scheduled"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfessionScheduledEvent {
+    #[topic]
+    pub schedule_id: u64,
+    pub event_version: u32,
+    pub timestamp: u64,
+    pub author: Address,
+    pub commitment: BytesN<32>,
+    pub publish_after: u64,
+}
+
+#[contractevent(topics = ["scheduled_published"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledPublishedEvent {
+    #[topic]
+    pub schedule_id: u64,
+    pub event_version: u32,
+    pub timestamp: u64,
+    pub confession_id: u64,
+}
+
 /// Pagination result returned by `list_confessions`.
 ///
 /// `has_next_page` is `true` when more items exist beyond this page.
@@ -105,6 +316,126 @@ pub struct Page {
     pub next_cursor: Option<u64>,
 }
 
+/// What remains of a confession after `erase_confession`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfessionTombstone {
+    pub id: u64,
+    /// Ledger timestamp of the erasure.
+    pub erased_at: u64,
+}
+
+/// Result of a bounded `purge_expired` sweep.
+///
+/// `next_cursor` is the last ID examined; pass it back to continue the sweep.
+/// It is `None` once the sweep has reached the newest confession.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurgeResult {
+    pub purged: u32,
+    pub next_cursor: Option<u64>,
+}
+
+/// Result of a bounded `migrate_confessions` sweep; `next_cursor` works as in
+/// [`PurgeResult`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrateResult {
+    pub migrated: u32,
+    pub next_cursor: Option<u64>,
+}
+
+/// Pagination result returned by `list_author_confessions`.
+///
+/// `next_cursor` is the index position to pass as `cursor` on the next call;
+/// it is `None` on the terminal page.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorPage {
+    pub items: Vec<u64>,
+    pub has_next_page: bool,
+    pub next_cursor: Option<u64>,
+}
+
+/// Per-author confession rate limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorRateLimitConfig {
+    pub max_confessions_per_window: u32,
+    pub window_seconds: u64,
+}
+
+/// Rolling window state tracked per author.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorWindow {
+    pub window_start: u64,
+    pub count: u32,
+}
+
+/// A moderator-imposed ban on creating confessions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorSuspension {
+    pub moderator: Address,
+    /// Ledger timestamp the suspension was imposed at.
+    pub suspended_at: u64,
+    /// Ledger timestamp at which the suspension lapses.
+    pub until: u64,
+    /// Off-chain moderation reason code.
+    pub reason_code: u32,
+}
+
+/// A confession committed to now and revealed with `publish_scheduled`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledConfession {
+    pub id: u64,
+    pub author: Address,
+    /// `sha256(content_hash || salt)`; see [`compute_schedule_commitment`].
+    pub commitment: BytesN<32>,
+    /// Ledger timestamp from which the confession may be published.
+    pub publish_after: u64,
+    pub scheduled_at: u64,
+}
+
+/// An author rotation that has not moved the whole index yet.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorRotation {
+    pub new_author: Address,
+    /// Owner or admin who approved a recovery; `None` when both keys signed.
+    pub recovered_by: Option<Address>,
+    /// First slot of the old author's index still to move.
+    pub next_slot: u64,
+    /// Confessions moved so far.
+    pub moved: u32,
+}
+
+/// An admin-approved recovery the old key can still cancel.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRecovery {
+    pub new_author: Address,
+    pub approved_by: Address,
+    pub requested_at: u64,
+    /// Ledger timestamp from which `execute_recovery` may run.
+    pub executable_at: u64,
+}
+
+/// Off-chain authorization attached to a relayed mutation.
+///
+/// `signature` is the ed25519 signature by `public_key` over
+/// [`relay_message`] for the target method and arguments.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RelayAuth {
+    pub public_key: BytesN<32>,
+    pub nonce: u64,
+    pub expiry: u64,
+    pub signature: BytesN<64>,
+}
+
 /// Storage keys used by the contract.
 #[contracttype]
 pub enum DataKey {
@@ -114,742 +445,3366 @@ pub enum DataKey {
     Confession(u64),
     /// Maps content_hash → confession_id for uniqueness checks.
     HashIndex(BytesN<32>),
-    /// Tracks confession IDs owned by an author.
+    /// Legacy instance-storage index of an author's confession IDs, replaced
+    /// by `AuthorBucket`. Folded in by `migrate_author_index`.
     AuthorConfessions(Address),
-    /// Contract admin address.
+    /// Number of entries in an author's confession index.
+    AuthorConfessionCount(Address),
+    /// Fixed-size bucket of an author's confession IDs (persistent storage).
+    /// Erased confessions leave a `0` in their slot so positions stay stable.
+    AuthorBucket(Address, u32),
+    /// Position of a confession within its author's index.
+    AuthorIndexPos(u64),
+    /// Number of slots in an author's index cleared by `erase_confession`.
+    AuthorErasedCount(Address),
+    /// Tombstone left behind by `erase_confession`.
+    Tombstone(u64),
+    /// Per-author confession rate limit configuration.
+    AuthorRateLimitConfig,
+    /// Rolling rate window state for an author.
+    AuthorWindow(Address),
+    /// Legacy admin address. Roles now live in `access_control`; deployments
+    /// that still carry this key fold it in with `migrate_legacy_admin`.
     Admin,
-    /// Per-caller sequencing nonce for replay protection.
+    /// Legacy per-caller sequencing nonce (lowest unused nonce). Read once
+    /// to seed `NonceWindow` for callers that predate windowed nonces.
     CallerNonce(Address),
+    /// Per-caller sliding nonce window for replay protection.
+    NonceWindow(Address),
     /// Event nonce for confession events.
     EventNonceConfession(u64),
+    /// Active or lapsed suspension of an author (persistent storage).
+    Suspension(Address),
+    /// The next scheduled-confession ID to assign.
+    NextScheduleId,
+    /// Pending scheduled confession by ID (persistent storage).
+    Scheduled(u64),
+    /// Admin-managed set of labels moderators may apply.
+    LabelVocabulary,
+    /// IDs of an author's unpublished scheduled confessions (persistent).
+    AuthorSchedules(Address),
+    /// Unfinished rotation away from an author (persistent).
+    Rotation(Address),
+    /// Old author whose unfinished rotation moves into this key (persistent).
+    RotationInto(Address),
+    /// Recovery of an author's key waiting out its delay (persistent).
+    PendingRecovery(Address),
+    /// Pseudonymous confession holding an unrevealed authorship commitment,
+    /// by commitment (persistent). Cleared by `prove_authorship` and
+    /// `erase_confession`.
+    CommitmentIndex(BytesN<32>),
 }
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum ReplayError {
+    /// Nonce was already consumed (or has slid below the window).
     InvalidNonce = 1,
+    /// The request's expiry timestamp has passed.
+    RequestExpired = 2,
+    /// Nonce is beyond the accepted window; consume lower nonces first.
+    NonceTooFarAhead = 3,
 }
 
-fn expected_nonce(env: &Env, caller: &Address) -> u64 {
-    env.storage()
+/// Sliding replay window for one caller.
+///
+/// `base` is the lowest unused nonce. Bit `i` of `used` is set when nonce
+/// `base + i` has already been consumed. Any unused nonce in
+/// `[base, base + NONCE_WINDOW_SIZE)` is accepted exactly once, so parallel
+/// workers can submit for the same caller without serializing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NonceWindow {
+    pub base: u64,
+    pub used: u64,
+}
+
+fn nonce_window(env: &Env, caller: &Address) -> NonceWindow {
+    if let Some(window) = env
+        .storage()
         .instance()
-        .get(&DataKey::CallerNonce(caller.clone()))
-        .unwrap_or(1u64)
+        .get(&DataKey::NonceWindow(caller.clone()))
+    {
+        return window;
+    }
+    NonceWindow {
+        base: env
+            .storage()
+            .instance()
+            .get(&DataKey::CallerNonce(caller.clone()))
+            .unwrap_or(1u64),
+        used: 0,
+    }
+}
+
+fn expected_nonce(env: &Env, caller: &Address) -> u64 {
+    nonce_window(env, caller).base
 }
 
-fn consume_nonce(env: &Env, caller: &Address, nonce: u64) -> Result<(), ReplayError> {
-    let expected = expected_nonce(env, caller);
-    if nonce != expected {
+fn consume_nonce(
+    env: &Env,
+    caller: &Address,
+    nonce: u64,
+    expires_at: Option<u64>,
+) -> Result<(), ReplayError> {
+    if let Some(expires_at) = expires_at {
+        if env.ledger().timestamp() > expires_at {
+            return Err(ReplayError::RequestExpired);
+        }
+    }
+
+    let mut window = nonce_window(env, caller);
+    if nonce < window.base {
         return Err(ReplayError::InvalidNonce);
     }
+    let offset = nonce - window.base;
+    if offset >= NONCE_WINDOW_SIZE {
+        return Err(ReplayError::NonceTooFarAhead);
+    }
+    if window.used & (1u64 << offset) != 0 {
+        return Err(ReplayError::InvalidNonce);
+    }
+
+    window.used |= 1u64 << offset;
+    // Slide past the contiguous run of consumed nonces at the bottom.
+    let consumed = window.used.trailing_ones() as u64;
+    if consumed > 0 {
+        window.base += consumed;
+        window.used = window.used.checked_shr(consumed as u32).unwrap_or(0);
+    }
 
     env.storage()
         .instance()
-        .set(&DataKey::CallerNonce(caller.clone()), &(expected + 1));
+        .set(&DataKey::NonceWindow(caller.clone()), &window);
+    checkpoint::record_nonce_consumed(env);
     Ok(())
 }
 
-fn bump_confession_event_nonce(env: &Env, id: u64) -> u64 {
-    let key = DataKey::EventNonceConfession(id);
-    let next = env
-        .storage()
-        .instance()
-        .get(&key)
-        .unwrap_or(0u64)
-        .checked_add(1)
-        .expect("event nonce overflow");
-    env.storage().instance().set(&key, &next);
-    next
-}
-
-// ─── Contract ───
-
-#[contract]
-pub struct ConfessionRegistry;
+/// XDR prefix of an `ScVal::Address(ScAddress::Account(PublicKey::Ed25519(_)))`.
+const ACCOUNT_ADDRESS_XDR_PREFIX: [u8; 12] = [0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0];
 
-#[contractimpl]
-impl ConfessionRegistry {
-    // ─── Initialization ───
+/// Stellar account address controlled by an ed25519 public key.
+pub fn relay_signer_address(env: &Env, public_key: &BytesN<32>) -> Address {
+    let mut xdr = Bytes::from_array(env, &ACCOUNT_ADDRESS_XDR_PREFIX);
+    xdr.append(&Bytes::from(public_key.clone()));
+    Address::from_xdr(env, &xdr).expect("invalid relay public key")
+}
 
-    /// Initialize the contract with an admin address.
-    pub fn initialize(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
-        }
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::NextId, &1u64);
+/// Message a relayed mutation must be signed over:
+/// the XDR encoding of `(contract, method, args, nonce, expiry)`.
+pub fn relay_message(
+    env: &Env,
+    contract: &Address,
+    method: &Symbol,
+    args: Vec<Val>,
+    nonce: u64,
+    expiry: u64,
+) -> Bytes {
+    (contract.clone(), method.clone(), args, nonce, expiry).to_xdr(env)
+}
 
-        // Also initialize common access control
-        access_control::init_owner(&env, &admin).expect("owner initialization failed");
-    }
+/// Verify a relayed request and consume the signer's nonce.
+///
+/// Returns the signer's account address, which then acts as the caller.
+/// An invalid signature aborts the invocation inside `ed25519_verify`.
+fn authenticate_relayed(
+    env: &Env,
+    auth: &RelayAuth,
+    method: &str,
+    args: Vec<Val>,
+) -> Result<Address, ReplayError> {
+    let message = relay_message(
+        env,
+        &env.current_contract_address(),
+        &Symbol::new(env, method),
+        args,
+        auth.nonce,
+        auth.expiry,
+    );
+    env.crypto()
+        .ed25519_verify(&auth.public_key, &message, &auth.signature);
+
+    let signer = relay_signer_address(env, &auth.public_key);
+    consume_nonce(env, &signer, auth.nonce, Some(auth.expiry))?;
+    Ok(signer)
+}
 
-    // ─── Governance ───
+fn author_confession_count(env: &Env, author: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuthorConfessionCount(author.clone()))
+        .unwrap_or(0u64)
+}
 
-    pub fn set_quorum(env: Env, threshold: u32) {
-        let mut config = governance::get_config(&env);
-        config.quorum_threshold = threshold;
-        let owner = access_control::get_owner(&env)
-            .expect("owner must exist before configuring governance");
-        governance::set_config(&env, &owner, config);
+/// Move IDs from the legacy `DataKey::AuthorConfessions` instance index into
+/// the author's buckets and drop the legacy key. Every index write runs this
+/// first, so legacy IDs keep their place ahead of newer ones. Returns the
+/// number of IDs moved.
+fn migrate_author_index(env: &Env, author: &Address) -> u32 {
+    let legacy_key = DataKey::AuthorConfessions(author.clone());
+    let Some(ids) = env
+        .storage()
+        .instance()
+        .get::<DataKey, Vec<u64>>(&legacy_key)
+    else {
+        return 0;
+    };
+    env.storage().instance().remove(&legacy_key);
+    for id in ids.iter() {
+        push_author_confession(env, author, id);
     }
+    ids.len()
+}
 
-    pub fn gov_propose(
-        env: Env,
-        proposer: Address,
-        action: governance::model::CriticalAction,
-    ) -> u64 {
-        governance::propose(&env, proposer, action)
+/// Append `id` to the author's index in O(1): only the tail bucket is touched.
+fn append_author_confession(env: &Env, author: &Address, id: u64) {
+    // Moved buckets are gone; an append would land in a slot the rotation
+    // never visits.
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Rotation(author.clone()))
+    {
+        panic!("author rotation in progress");
     }
+    migrate_author_index(env, author);
+    push_author_confession(env, author, id);
+}
 
-    pub fn gov_approve(env: Env, approver: Address, id: u64) {
-        governance::approve(&env, approver, id)
-    }
+fn push_author_confession(env: &Env, author: &Address, id: u64) {
+    let count = author_confession_count(env, author);
+    let bucket = (count / AUTHOR_INDEX_BUCKET_SIZE as u64) as u32;
+    let key = DataKey::AuthorBucket(author.clone(), bucket);
 
-    pub fn gov_revoke(env: Env, actor: Address, id: u64) {
-        governance::revoke(&env, actor, id)
-    }
+    let mut ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    ids.push_back(id);
+    env.storage().persistent().set(&key, &ids);
+    env.storage().persistent().set(
+        &DataKey::AuthorConfessionCount(author.clone()),
+        &(count + 1),
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::AuthorIndexPos(id), &count);
+}
 
-    pub fn gov_execute(env: Env, executor: Address, id: u64) {
-        governance::execute(&env, executor, id)
+/// Clear `id`'s slot in the author's index without shifting later entries.
+fn remove_author_confession(env: &Env, author: &Address, id: u64) {
+    migrate_author_index(env, author);
+    let pos_key = DataKey::AuthorIndexPos(id);
+    let Some(pos) = env.storage().persistent().get::<DataKey, u64>(&pos_key) else {
+        return;
+    };
+    let bucket = (pos / AUTHOR_INDEX_BUCKET_SIZE as u64) as u32;
+    let slot = (pos % AUTHOR_INDEX_BUCKET_SIZE as u64) as u32;
+    let key = DataKey::AuthorBucket(author.clone(), bucket);
+
+    let mut ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    if ids.get(slot) != Some(id) {
+        return;
     }
+    ids.set(slot, 0);
+    env.storage().persistent().set(&key, &ids);
+    env.storage().persistent().remove(&pos_key);
 
-    // ─── Create ───
+    let erased_key = DataKey::AuthorErasedCount(author.clone());
+    let erased: u64 = env.storage().persistent().get(&erased_key).unwrap_or(0);
+    env.storage().persistent().set(&erased_key, &(erased + 1));
+}
 
-    /// Create a new confession.
-    ///
-    /// - `author`: the address creating the confession (must authorize).
-    /// - `content_hash`: 32-byte hash of the confession content.
-    /// - `timestamp`: client-provided timestamp.
-    ///
-    /// Returns the newly assigned confession ID.
-    ///
-    /// Emits: `("confession_created", id)` → `(author, content_hash, timestamp)`
-    pub fn create_confession(
-        env: Env,
-        author: Address,
-        content_hash: BytesN<32>,
-        timestamp: u64,
-    ) -> u64 {
-        // Require author authorization
-        author.require_auth();
+fn author_rate_limit_config(env: &Env) -> AuthorRateLimitConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::AuthorRateLimitConfig)
+        .unwrap_or(AuthorRateLimitConfig {
+            max_confessions_per_window: DEFAULT_MAX_CONFESSIONS_PER_WINDOW,
+            window_seconds: DEFAULT_AUTHOR_RATE_WINDOW_SECONDS,
+        })
+}
 
-        // Check if paused — use shared emergency pause module
-        emergency_pause::assert_not_paused(&env).unwrap_or_else(|err| panic!("{}", err as u32));
+fn assert_author_within_rate_limit(env: &Env, author: &Address) {
+    let cfg = author_rate_limit_config(env);
+    let now = env.ledger().timestamp();
+    let key = DataKey::AuthorWindow(author.clone());
 
-        // Enforce uniqueness on content_hash
-        if env
-            .storage()
-            .instance()
-            .has(&DataKey::HashIndex(content_hash.clone()))
-        {
-            panic!("confession with this content hash already exists");
-        }
+    let mut state: AuthorWindow = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(AuthorWindow {
+            window_start: now,
+            count: 0,
+        });
 
-        let mut author_ids: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey::AuthorConfessions(author.clone()))
-            .unwrap_or_else(|| Vec::new(&env));
-        if author_ids.len() >= MAX_AUTHOR_CONFESSIONS_PER_AUTHOR {
-            panic!("{}", REGISTRY_PAYLOAD_TOO_LONG);
-        }
+    if now.saturating_sub(state.window_start) >= cfg.window_seconds {
+        state.window_start = now;
+        state.count = 0;
+    }
 
-        // Allocate ID
-        let id: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::NextId)
-            .unwrap_or(1u64);
-        env.storage().instance().set(&DataKey::NextId, &(id + 1));
+    if state.count >= cfg.max_confessions_per_window {
+        panic!("{}", REGISTRY_RATE_LIMITED);
+    }
 
-        // Build record
-        let confession = Confession {
-            id,
-            author: author.clone(),
-            content_hash: content_hash.clone(),
-            created_at: timestamp,
-            updated_at: 0,
-            status: ConfessionStatus::Active,
-        };
+    state.count += 1;
+    env.storage().persistent().set(&key, &state);
+}
 
-        // Persist
-        env.storage()
-            .instance()
-            .set(&DataKey::Confession(id), &confession);
-        env.storage()
-            .instance()
-            .set(&DataKey::HashIndex(content_hash.clone()), &id);
+fn label_vocabulary(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&DataKey::LabelVocabulary)
+        .unwrap_or_else(|| Vec::new(env))
+}
 
-        // Track author → confession index
-        author_ids.push_back(id);
-        env.storage()
-            .instance()
-            .set(&DataKey::AuthorConfessions(author.clone()), &author_ids);
+/// Load a confession a moderator may label: it must exist and not be deleted.
+fn labelable_confession(env: &Env, moderator: &Address, id: u64) -> Confession {
+    emergency_pause::assert_not_paused(env, PauseScope::Update)
+        .unwrap_or_else(|err| panic!("{}", err as u32));
 
-        // Emit event
-        ConfessionCreatedEvent {
-            id,
-            event_version: events::EVENT_VERSION_V1,
-            nonce: bump_confession_event_nonce(&env, id),
-            timestamp,
-            author,
-            content_hash,
-            correlation_id: None,
-        }
-        .publish(&env);
+    if !is_moderator(env, moderator) {
+        panic!("unauthorized: only moderator can manage labels");
+    }
+    let confession = load_confession(env, id).expect("confession not found");
+    if confession.status == ConfessionStatus::Deleted {
+        panic!("confession is deleted and cannot be labeled");
+    }
+    confession
+}
 
-        id
+fn active_suspension(env: &Env, author: &Address) -> Option<AuthorSuspension> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, AuthorSuspension>(&DataKey::Suspension(author.clone()))
+        .filter(|s| env.ledger().timestamp() < s.until)
+}
+
+fn assert_author_not_suspended(env: &Env, author: &Address) {
+    if active_suspension(env, author).is_some() {
+        panic!("author is suspended");
     }
+}
 
-    // ─── Read ───
+fn author_schedules(env: &Env, author: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuthorSchedules(author.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
 
-    /// Get a confession by ID.
-    pub fn get_confession(env: Env, id: u64) -> Confession {
-        env.storage()
-            .instance()
-            .get(&DataKey::Confession(id))
-            .expect("confession not found")
+fn set_author_schedules(env: &Env, author: &Address, ids: &Vec<u64>) {
+    let key = DataKey::AuthorSchedules(author.clone());
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, ids);
     }
+}
 
-    /// Get a confession ID by its content hash.
-    pub fn get_by_hash(env: Env, content_hash: BytesN<32>) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::HashIndex(content_hash))
-            .expect("no confession with that hash")
+fn assert_not_rotating(env: &Env, author: &Address) {
+    let storage = env.storage().persistent();
+    if storage.has(&DataKey::Rotation(author.clone()))
+        || storage.has(&DataKey::RotationInto(author.clone()))
+    {
+        panic!("author rotation in progress");
+    }
+}
+
+/// Start moving `old`'s confessions and pending schedules to `new` and run
+/// the first batch. Returns `true` once the rotation is complete.
+fn start_rotation(env: &Env, old: Address, new: Address, recovered_by: Option<Address>) -> bool {
+    emergency_pause::assert_not_paused(env, PauseScope::Update)
+        .unwrap_or_else(|err| panic!("{}", err as u32));
+
+    if old == new {
+        panic!("new author must differ from old author");
+    }
+    assert_author_not_suspended(env, &old);
+    assert_author_not_suspended(env, &new);
+    assert_not_rotating(env, &old);
+    assert_not_rotating(env, &new);
+    // Neither key can schedule while the rotation runs, so checking the cap
+    // here keeps `finish_rotation` within it.
+    if author_schedules(env, &old).len() + author_schedules(env, &new).len()
+        > MAX_PENDING_SCHEDULES_PER_AUTHOR
+    {
+        panic!("too many pending scheduled confessions");
+    }
+    migrate_author_index(env, &old);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::RotationInto(new.clone()), &old);
+    let rotation = AuthorRotation {
+        new_author: new,
+        recovered_by,
+        next_slot: 0,
+        moved: 0,
+    };
+    advance_rotation(env, old, rotation)
+}
+
+/// Move the next `MAX_ROTATION_INDEX_LEN` slots of `old`'s index to the new
+/// author, rewriting `author` on each stored record. Finishes the rotation
+/// once the index is drained; otherwise saves the cursor for
+/// `continue_rotation`.
+fn advance_rotation(env: &Env, old: Address, mut rotation: AuthorRotation) -> bool {
+    let new = rotation.new_author.clone();
+    let total = author_confession_count(env, &old);
+    let end = total.min(rotation.next_slot.saturating_add(MAX_ROTATION_INDEX_LEN));
+
+    // `next_slot` is always a bucket boundary, so every bucket in range is
+    // moved whole.
+    let bucket_size = AUTHOR_INDEX_BUCKET_SIZE as u64;
+    let first = (rotation.next_slot / bucket_size) as u32;
+    let last = end.div_ceil(bucket_size) as u32;
+    for bucket in first..last {
+        let key = DataKey::AuthorBucket(old.clone(), bucket);
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        for id in ids.iter().filter(|id| *id != 0) {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::AuthorIndexPos(id));
+            // Confessions purged before purges cleared index slots leave
+            // their ID behind; drop it here.
+            let Some(mut confession) = load_confession(env, id) else {
+                continue;
+            };
+            confession.author = Some(new.clone());
+            save_confession(env, &confession);
+            append_author_confession(env, &new, id);
+            rotation.moved += 1;
+        }
+        env.storage().persistent().remove(&key);
     }
+    rotation.next_slot = end;
 
-    /// Get all confession IDs for an author.
-    pub fn get_author_confessions(env: Env, author: Address) -> Vec<u64> {
+    if end < total {
         env.storage()
-            .instance()
-            .get(&DataKey::AuthorConfessions(author))
-            .unwrap_or_else(|| Vec::new(&env))
+            .persistent()
+            .set(&DataKey::Rotation(old), &rotation);
+        return false;
     }
+    finish_rotation(env, old, rotation);
+    true
+}
 
-    /// List confessions with cursor-based pagination.
-    ///
-    /// - `cursor`: exclusive lower bound (last seen ID). Pass `None` to start from the beginning.
-    /// - `limit`: maximum number of items to return (capped at 50).
-    ///
-    /// Returns a `Page<Confession>` with `has_next_page` and `next_cursor` so callers
-    /// can detect terminal pages without guessing.
-    pub fn list_confessions(env: Env, cursor: Option<u64>, limit: u32) -> Page {
-        let limit = limit.min(50) as u64;
-        let start = cursor.unwrap_or(0) + 1;
-        let total: u64 = env
+/// Hand `old`'s pending schedules to the new author, clear `old`'s index
+/// and the rotation state, and announce the rotation.
+fn finish_rotation(env: &Env, old: Address, rotation: AuthorRotation) {
+    let new = rotation.new_author;
+    let mut schedules = author_schedules(env, &new);
+    for id in author_schedules(env, &old).iter() {
+        let key = DataKey::Scheduled(id);
+        if let Some(mut scheduled) = env
             .storage()
-            .instance()
-            .get(&DataKey::NextId)
-            .unwrap_or(1u64)
-            .saturating_sub(1);
+            .persistent()
+            .get::<DataKey, ScheduledConfession>(&key)
+        {
+            scheduled.author = new.clone();
+            env.storage().persistent().set(&key, &scheduled);
+            schedules.push_back(id);
+        }
+    }
+    set_author_schedules(env, &new, &schedules);
+    set_author_schedules(env, &old, &Vec::new(env));
+
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::AuthorConfessionCount(old.clone()));
+    storage.remove(&DataKey::AuthorErasedCount(old.clone()));
+    storage.remove(&DataKey::Rotation(old.clone()));
+    storage.remove(&DataKey::RotationInto(new.clone()));
+
+    AuthorRotatedEvent {
+        old_author: old,
+        new_author: new,
+        event_version: events::EVENT_VERSION_V1,
+        timestamp: env.ledger().timestamp(),
+        moved: rotation.moved,
+        recovered_by: rotation.recovered_by,
+    }
+    .publish(env);
+}
 
-        let mut items: Vec<Confession> = Vec::new(&env);
-        let mut id = start;
-        // Fetch up to limit+1 to detect whether a next page exists.
-        while id <= total && items.len() as u64 <= limit {
-            if let Some(c) = env
-                .storage()
-                .instance()
-                .get::<DataKey, Confession>(&DataKey::Confession(id))
-            {
-                items.push_back(c);
-            }
-            id += 1;
+/// Compute the pseudonymous authorship commitment `sha256(author_xdr || salt)`.
+///
+/// Clients should compute this off-chain; it is exposed for tests and tooling.
+pub fn compute_author_commitment(env: &Env, author: &Address, salt: &BytesN<32>) -> BytesN<32> {
+    let mut payload: Bytes = author.clone().to_xdr(env);
+    payload.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&payload).into()
+}
+
+/// Compute the scheduled-publication commitment `sha256(content_hash || salt)`.
+///
+/// Clients should compute this off-chain; it is exposed for tests and tooling.
+pub fn compute_schedule_commitment(
+    env: &Env,
+    content_hash: &BytesN<32>,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let mut payload = Bytes::from(content_hash.clone());
+    payload.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&payload).into()
+}
+
+fn assert_content_hash_unused(env: &Env, content_hash: &BytesN<32>) {
+    if env
+        .storage()
+        .instance()
+        .has(&DataKey::HashIndex(content_hash.clone()))
+    {
+        panic!("confession with this content hash already exists");
+    }
+}
+
+/// Allocate an ID and persist a new active confession plus its hash index entry.
+fn insert_confession(
+    env: &Env,
+    author: Option<Address>,
+    author_commitment: Option<BytesN<32>>,
+    content_hash: &BytesN<32>,
+    timestamp: u64,
+    expires_at: Option<u64>,
+) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextId)
+        .unwrap_or(1u64);
+    env.storage().instance().set(&DataKey::NextId, &(id + 1));
+
+    let confession = Confession {
+        id,
+        author,
+        author_commitment,
+        content_hash: content_hash.clone(),
+        created_at: timestamp,
+        updated_at: 0,
+        status: ConfessionStatus::Active,
+        expires_at,
+        labels: Vec::new(env),
+    };
+
+    save_confession(env, &confession);
+    env.storage()
+        .instance()
+        .set(&DataKey::HashIndex(content_hash.clone()), &id);
+    checkpoint::record_created(env, &confession);
+    emergency_pause::record_breaker_write(env, BreakerMetric::Confessions, PauseScope::Create);
+    id
+}
+
+fn is_expired(env: &Env, confession: &Confession) -> bool {
+    confession
+        .expires_at
+        .is_some_and(|expires_at| env.ledger().timestamp() >= expires_at)
+}
+
+/// Load confession `id` in the current layout, upgrading a record still
+/// stored in the pre-1.0 `LegacyConfession` layout. The upgraded form is only
+/// persisted when the caller writes the record back (or by
+/// `migrate_confessions`).
+pub(crate) fn load_confession(env: &Env, id: u64) -> Option<Confession> {
+    let key = DataKey::Confession(id);
+    let Some(raw) = env
+        .storage()
+        .instance()
+        .get::<DataKey, Map<Symbol, Val>>(&key)
+    else {
+        // Ephemeral confessions postdate 1.0 and are never legacy records.
+        return env.storage().persistent().get(&key);
+    };
+    // A struct decodes only from a map with exactly its fields, and a failed
+    // decode traps, so pick the layout by field count.
+    if !is_legacy_record(&raw) {
+        return Some(Confession::try_from_val(env, &raw.to_val()).expect("unreadable confession"));
+    }
+    let legacy = LegacyConfession::try_from_val(env, &raw.to_val()).expect("unreadable confession");
+    Some(Confession {
+        id: legacy.id,
+        author: Some(legacy.author),
+        author_commitment: None,
+        content_hash: legacy.content_hash,
+        created_at: legacy.created_at,
+        updated_at: legacy.updated_at,
+        status: legacy.status,
+        expires_at: None,
+        labels: Vec::new(env),
+    })
+}
+
+/// Store `confession`. Ephemeral confessions go to persistent storage with a
+/// TTL that runs `EPHEMERAL_PURGE_GRACE_LEDGERS` past their expiry, so they
+/// stay out of the instance entry and leave room for `purge_expired`.
+fn save_confession(env: &Env, confession: &Confession) {
+    let key = DataKey::Confession(confession.id);
+    let Some(expires_at) = confession.expires_at else {
+        env.storage().instance().set(&key, confession);
+        return;
+    };
+    let ledgers = expires_at.saturating_sub(env.ledger().timestamp()) / LEDGER_SECONDS
+        + EPHEMERAL_PURGE_GRACE_LEDGERS as u64;
+    let ttl = ledgers.min(env.storage().max_ttl() as u64) as u32;
+    let storage = env.storage().persistent();
+    storage.set(&key, confession);
+    storage.extend_ttl(&key, ttl, ttl);
+}
+
+fn remove_confession(env: &Env, id: u64) {
+    let key = DataKey::Confession(id);
+    env.storage().instance().remove(&key);
+    env.storage().persistent().remove(&key);
+}
+
+fn is_legacy_record(raw: &Map<Symbol, Val>) -> bool {
+    raw.len() == LEGACY_CONFESSION_FIELDS
+}
+
+/// Present an expired confession as `Expired` to readers.
+fn with_read_status(env: &Env, mut confession: Confession) -> Confession {
+    if confession.status != ConfessionStatus::Deleted && is_expired(env, &confession) {
+        confession.status = ConfessionStatus::Expired;
+    }
+    confession
+}
+
+/// Owner or admin in the shared `access_control` role set.
+fn is_registry_admin(env: &Env, addr: &Address) -> bool {
+    access_control::is_authorized(env, addr).expect("contract not initialized")
+}
+
+/// Moderators (owner, admins and operators) may act on any confession.
+fn is_moderator(env: &Env, addr: &Address) -> bool {
+    is_registry_admin(env, addr) || access_control::is_operator(env, addr)
+}
+
+fn bump_confession_event_nonce(env: &Env, id: u64) -> u64 {
+    let key = DataKey::EventNonceConfession(id);
+    let next = env
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(0u64)
+        .checked_add(1)
+        .expect("event nonce overflow");
+    env.storage().instance().set(&key, &next);
+    checkpoint::record_event(env, id);
+    next
+}
+
+/// Reject empty correlation IDs. `Symbol` already limits the charset to
+/// `[a-zA-Z0-9_]` and the length to 32 characters.
+fn assert_valid_correlation_id(env: &Env, correlation_id: &Option<Symbol>) {
+    if let Some(id) = correlation_id {
+        if *id == Symbol::new(env, "") {
+            panic!("invalid correlation id");
         }
+    }
+}
 
-        let has_next_page = items.len() as u64 > limit;
-        if has_next_page {
-            items.pop_back();
+/// Body of `create_confession` once `author` has been authenticated.
+fn create_confession_as(
+    env: &Env,
+    author: Address,
+    content_hash: BytesN<32>,
+    timestamp: u64,
+    expires_at: Option<u64>,
+    correlation_id: Option<Symbol>,
+) -> u64 {
+    // Check if paused — use shared emergency pause module
+    emergency_pause::assert_not_paused(env, PauseScope::Create)
+        .unwrap_or_else(|err| panic!("{}", err as u32));
+
+    assert_valid_correlation_id(env, &correlation_id);
+
+    // Enforce uniqueness on content_hash
+    assert_content_hash_unused(env, &content_hash);
+
+    assert_author_not_suspended(env, &author);
+    assert_author_within_rate_limit(env, &author);
+
+    if let Some(expires_at) = expires_at {
+        if expires_at <= env.ledger().timestamp() {
+            panic!("expiry must be in the future");
         }
+    }
 
-        let next_cursor = if has_next_page {
-            items.last().map(|c| c.id)
-        } else {
-            None
-        };
+    let id = insert_confession(
+        env,
+        Some(author.clone()),
+        None,
+        &content_hash,
+        timestamp,
+        expires_at,
+    );
+
+    // Track author → confession index
+    append_author_confession(env, &author, id);
+
+    // Emit event
+    ConfessionCreatedEvent {
+        id,
+        event_version: events::EVENT_VERSION_V1,
+        nonce: bump_confession_event_nonce(env, id),
+        timestamp,
+        author,
+        content_hash,
+        correlation_id,
+    }
+    .publish(env);
 
-        Page {
-            items,
-            has_next_page,
-            next_cursor,
-        }
+    id
+}
+
+/// Body of `update_status` once `caller` has been authenticated.
+fn update_status_as(
+    env: &Env,
+    caller: Address,
+    id: u64,
+    new_status: ConfessionStatus,
+    timestamp: u64,
+    correlation_id: Option<Symbol>,
+) {
+    // Check if paused — use shared emergency pause module
+    emergency_pause::assert_not_paused(env, PauseScope::Update)
+        .unwrap_or_else(|err| panic!("{}", err as u32));
+
+    assert_valid_correlation_id(env, &correlation_id);
+
+    let mut confession = load_confession(env, id).expect("confession not found");
+
+    // Terminal-state guard — a deleted confession is immutable.
+    // Prevents resurrection (Deleted → Active) and double-delete side effects.
+    if confession.status == ConfessionStatus::Deleted {
+        panic!("confession is deleted and cannot be updated");
+    }
+    if is_expired(env, &confession) {
+        panic!("confession has expired and cannot be updated");
+    }
+    if new_status == ConfessionStatus::Expired {
+        panic!("expired status is derived from expires_at");
     }
 
-    /// Get the total number of confessions created.
-    pub fn get_total_count(env: Env) -> u64 {
-        let next_id: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::NextId)
-            .unwrap_or(1u64);
-        next_id - 1
+    if confession.author.as_ref() != Some(&caller) && !is_moderator(env, &caller) {
+        panic!("unauthorized: only author or moderator can update status");
     }
 
-    /// Return the next valid nonce for a caller in sequenced mutation methods.
-    pub fn get_expected_nonce(env: Env, caller: Address) -> u64 {
-        expected_nonce(&env, &caller)
+    let old_status = confession.status.clone();
+    checkpoint::record_status_change(env, &confession, &new_status);
+    confession.status = new_status;
+    confession.updated_at = timestamp;
+
+    save_confession(env, &confession);
+
+    ConfessionUpdatedEvent {
+        id,
+        event_version: events::EVENT_VERSION_V1,
+        nonce: bump_confession_event_nonce(env, id),
+        timestamp,
+        old_status,
+        new_status: confession.status,
+        correlation_id,
     }
+    .publish(env);
+}
 
-    /// Replay-protected create_confession variant.
-    pub fn create_confession_seq(
-        env: Env,
-        author: Address,
-        content_hash: BytesN<32>,
-        timestamp: u64,
-        nonce: u64,
-    ) -> Result<u64, ReplayError> {
-        consume_nonce(&env, &author, nonce)?;
-        Ok(Self::create_confession(
-            env,
-            author,
-            content_hash,
-            timestamp,
-        ))
+/// Body of `delete_confession` once `caller` has been authenticated.
+fn delete_confession_as(
+    env: &Env,
+    caller: Address,
+    id: u64,
+    timestamp: u64,
+    correlation_id: Option<Symbol>,
+) {
+    // Check if paused — use shared emergency pause module
+    emergency_pause::assert_not_paused(env, PauseScope::Delete)
+        .unwrap_or_else(|err| panic!("{}", err as u32));
+
+    assert_valid_correlation_id(env, &correlation_id);
+
+    let mut confession = load_confession(env, id).expect("confession not found");
+
+    // Terminal-state guard — prevents double-delete and misleading updated_at stamps.
+    if confession.status == ConfessionStatus::Deleted {
+        panic!("confession is already deleted");
     }
 
-    // ─── Update Status ───
+    if confession.author.as_ref() != Some(&caller) && !is_moderator(env, &caller) {
+        panic!("unauthorized: only author or moderator can delete");
+    }
 
-    /// Update the status of a confession.
-    ///
-    /// Only the author or the contract admin can change status.
-    ///
-    /// Emits: `("confession_updated", id)` → `(old_status, new_status, timestamp)`
-    pub fn update_status(
-        env: Env,
-        caller: Address,
-        id: u64,
-        new_status: ConfessionStatus,
-        timestamp: u64,
-    ) {
-        caller.require_auth();
+    checkpoint::record_status_change(env, &confession, &ConfessionStatus::Deleted);
+    confession.status = ConfessionStatus::Deleted;
+    confession.updated_at = timestamp;
 
-        // Check if paused — use shared emergency pause module
-        emergency_pause::assert_not_paused(&env).unwrap_or_else(|err| panic!("{}", err as u32));
+    save_confession(env, &confession);
 
-        let mut confession: Confession = env
-            .storage()
-            .instance()
-            .get(&DataKey::Confession(id))
-            .expect("confession not found");
+    ConfessionDeletedEvent {
+        id,
+        event_version: events::EVENT_VERSION_V1,
+        nonce: bump_confession_event_nonce(env, id),
+        timestamp,
+        actor: caller,
+        correlation_id,
+    }
+    .publish(env);
+}
+
+// ─── Contract ───
+
+#[contract]
+pub struct ConfessionRegistry;
 
-        // Terminal-state guard — a deleted confession is immutable.
-        // Prevents resurrection (Deleted → Active) and double-delete side effects.
-        if confession.status == ConfessionStatus::Deleted {
-            panic!("confession is deleted and cannot be updated");
+#[contractimpl]
+impl ConfessionRegistry {
+    // ─── Initialization ───
+
+    /// Initialize the contract; `admin` becomes the `access_control` owner.
+    pub fn initialize(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) || access_control::get_owner(&env).is_ok()
+        {
+            panic!("already initialized");
         }
+        env.storage().instance().set(&DataKey::NextId, &1u64);
 
-        let admin: Address = env
+        access_control::init_owner(&env, &admin).expect("owner initialization failed");
+    }
+
+    /// Fold the legacy `DataKey::Admin` into the shared role set.
+    ///
+    /// The legacy admin must authorize. It becomes the owner if none is
+    /// recorded yet, otherwise an admin (unless it already is the owner).
+    /// The legacy key is removed afterwards, so this runs at most once.
+    pub fn migrate_legacy_admin(env: Env) {
+        let legacy: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("contract not initialized");
-
-        if caller != confession.author && caller != admin {
-            panic!("unauthorized: only author or admin can update status");
+            .expect("no legacy admin to migrate");
+        legacy.require_auth();
+
+        match access_control::get_owner(&env) {
+            Err(_) => access_control::init_owner(&env, &legacy)
+                .unwrap_or_else(|err| panic!("{}", err as u32)),
+            Ok(owner) if owner != legacy && !access_control::is_admin(&env, &legacy) => {
+                access_control::internal_grant_admin(&env, &legacy)
+                    .unwrap_or_else(|err| panic!("{}", err as u32))
+            }
+            Ok(_) => {}
         }
 
-        let old_status = confession.status.clone();
-        confession.status = new_status;
-        confession.updated_at = timestamp;
+        env.storage().instance().remove(&DataKey::Admin);
+    }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::Confession(id), &confession);
+    /// Rewrite confessions still stored in the pre-1.0 `LegacyConfession`
+    /// layout in the current one. Owner or admin only; bounded like
+    /// `purge_expired`.
+    ///
+    /// Reads already upgrade legacy records on the fly, so this only moves the
+    /// cost of the upgrade out of later writes.
+    pub fn migrate_confessions(
+        env: Env,
+        caller: Address,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> MigrateResult {
+        caller.require_auth();
+        if !is_registry_admin(&env, &caller) {
+            panic!("unauthorized: only admin can migrate confessions");
+        }
+        if limit == 0 {
+            panic!("limit must be positive");
+        }
+
+        let last_id = Self::get_total_count(env.clone());
+        let start = cursor.map_or(1, |c| c.saturating_add(1));
+        let end = start
+            .saturating_add(limit.min(MAX_MIGRATE_LIMIT) as u64 - 1)
+            .min(last_id);
+
+        let mut migrated = 0u32;
+        let mut id = start;
+        while id <= end {
+            let key = DataKey::Confession(id);
+            if let Some(raw) = env
+                .storage()
+                .instance()
+                .get::<DataKey, Map<Symbol, Val>>(&key)
+            {
+                if is_legacy_record(&raw) {
+                    let confession = load_confession(&env, id).expect("confession not found");
+                    env.storage().instance().set(&key, &confession);
+                    migrated += 1;
+                }
+            }
+            id += 1;
+        }
+
+        MigrateResult {
+            migrated,
+            next_cursor: if end < last_id { Some(end) } else { None },
+        }
+    }
+
+    /// Move `author`'s legacy instance-storage index into persistent
+    /// buckets. Anyone may call this; it only relocates IDs already recorded
+    /// for the author and is a no-op once done. Returns the number moved.
+    ///
+    /// Writes to the index migrate it automatically, but reads do not: run
+    /// this for each legacy author after upgrading so `list_author_confessions`
+    /// and `get_author_confession_count` include older confessions.
+    pub fn migrate_author_index(env: Env, author: Address) -> u32 {
+        migrate_author_index(&env, &author)
+    }
+
+    // ─── Roles ───
+
+    pub fn get_owner(env: Env) -> Address {
+        access_control::get_owner(&env).unwrap_or_else(|err| panic!("{}", err as u32))
+    }
+
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        access_control::is_admin(&env, &address)
+    }
+
+    pub fn is_operator(env: Env, address: Address) -> bool {
+        access_control::is_operator(&env, &address)
+    }
+
+    /// Grant the operator (moderator) role. Caller must be owner or admin.
+    pub fn grant_operator(env: Env, caller: Address, target: Address) {
+        access_control::grant_operator(&env, &caller, &target)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+    }
+
+    /// Revoke the operator (moderator) role. Caller must be owner or admin.
+    pub fn revoke_operator(env: Env, caller: Address, target: Address) {
+        access_control::revoke_operator(&env, &caller, &target)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+    }
+
+    pub fn is_guardian(env: Env, address: Address) -> bool {
+        access_control::is_guardian(&env, &address)
+    }
+
+    /// Grant the pause-only guardian role. Caller must be owner or admin.
+    pub fn grant_guardian(env: Env, caller: Address, target: Address) {
+        access_control::grant_guardian(&env, &caller, &target)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+    }
+
+    /// Revoke the guardian role. Caller must be owner or admin.
+    pub fn revoke_guardian(env: Env, caller: Address, target: Address) {
+        access_control::revoke_guardian(&env, &caller, &target)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+    }
+
+    // ─── Governance ───
+
+    pub fn set_quorum(env: Env, threshold: u32) {
+        let mut config = governance::get_config(&env);
+        config.quorum_threshold = threshold;
+        let owner = access_control::get_owner(&env)
+            .expect("owner must exist before configuring governance");
+        governance::set_config(&env, &owner, config);
+    }
+
+    pub fn gov_propose(
+        env: Env,
+        proposer: Address,
+        action: governance::model::CriticalAction,
+    ) -> u64 {
+        governance::propose(&env, proposer, action)
+    }
+
+    pub fn gov_approve(env: Env, approver: Address, id: u64) {
+        governance::approve(&env, approver, id)
+    }
+
+    pub fn gov_revoke(env: Env, actor: Address, id: u64) {
+        governance::revoke(&env, actor, id)
+    }
+
+    pub fn gov_execute(env: Env, executor: Address, id: u64) {
+        governance::execute(&env, executor, id)
+    }
+
+    // ─── Pause Scopes ───
+
+    /// Pause the whole registry for `duration_seconds` (owner, admin or
+    /// guardian). Lapses automatically; see `get_pause_state`.
+    pub fn pause(env: Env, caller: Address, reason: String, duration_seconds: u64) {
+        emergency_pause::pause(env, caller, reason, duration_seconds)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+    }
+
+    /// Lift the global pause early (owner/admin; guardians cannot unpause).
+    pub fn unpause(env: Env, caller: Address, reason: String) {
+        emergency_pause::unpause(env, caller, reason)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+    }
+
+    pub fn get_pause_state(env: Env) -> emergency_pause::PauseState {
+        emergency_pause::get_pause_state(&env)
+    }
+
+    /// Pause actions (global, scoped and governance), oldest first.
+    pub fn pause_history(
+        env: Env,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> emergency_pause::PauseHistoryPage {
+        emergency_pause::pause_history(&env, cursor, limit)
+    }
+
+    /// Pause one operation group (owner, admin or guardian). The global
+    /// pause overrides every scope.
+    pub fn pause_scope(
+        env: Env,
+        caller: Address,
+        scope: PauseScope,
+        reason: String,
+        duration_seconds: u64,
+    ) {
+        emergency_pause::pause_scope(&env, &caller, scope, reason, duration_seconds)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+    }
+
+    /// Lift a scope pause (owner/admin).
+    pub fn unpause_scope(env: Env, caller: Address, scope: PauseScope, reason: String) {
+        emergency_pause::unpause_scope(&env, &caller, scope, reason)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+    }
+
+    /// `true` if `scope` is paused directly or via the global pause.
+    pub fn is_scope_paused(env: Env, scope: PauseScope) -> bool {
+        emergency_pause::is_scope_paused(&env, scope)
+    }
+
+    /// Set the circuit breaker thresholds (owner or admin). More than
+    /// `max_writes_per_window` confessions in one window pauses the create
+    /// scope for `trip_duration_seconds`.
+    pub fn configure_circuit_breaker(env: Env, caller: Address, config: BreakerConfig) {
+        emergency_pause::configure_circuit_breaker(&env, &caller, &config)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+    }
+
+    pub fn get_circuit_breaker_config(env: Env) -> BreakerConfig {
+        emergency_pause::circuit_breaker::get_config(&env)
+    }
+
+    // ─── Create ───
+
+    /// Create a new confession.
+    ///
+    /// - `author`: the address creating the confession (must authorize).
+    /// - `content_hash`: 32-byte hash of the confession content.
+    /// - `timestamp`: client-provided timestamp.
+    /// - `correlation_id`: optional caller trace ID, published in the event.
+    ///
+    /// Returns the newly assigned confession ID.
+    ///
+    /// Emits: `("confession_created", id)` → `(author, content_hash, timestamp, correlation_id)`
+    pub fn create_confession(
+        env: Env,
+        author: Address,
+        content_hash: BytesN<32>,
+        timestamp: u64,
+        correlation_id: Option<Symbol>,
+    ) -> u64 {
+        // Require author authorization
+        author.require_auth();
+
+        create_confession_as(&env, author, content_hash, timestamp, None, correlation_id)
+    }
+
+    /// Create an ephemeral confession that expires at ledger time `expires_at`.
+    ///
+    /// Once expired, reads report it as `Expired`, it can no longer be
+    /// updated, and anyone may remove it with `purge_expired`.
+    pub fn create_ephemeral_confession(
+        env: Env,
+        author: Address,
+        content_hash: BytesN<32>,
+        timestamp: u64,
+        expires_at: u64,
+    ) -> u64 {
+        author.require_auth();
+        create_confession_as(
+            &env,
+            author,
+            content_hash,
+            timestamp,
+            Some(expires_at),
+            None,
+        )
+    }
+
+    /// Create a pseudonymous confession that stores only an authorship commitment.
+    ///
+    /// - `submitter`: the account paying for and authorizing the call (e.g. the
+    ///   backend relayer). It is rate-limited but never stored or emitted.
+    /// - `author_commitment`: `sha256(author_xdr || salt)`; see
+    ///   [`compute_author_commitment`]. Use a fresh salt for every confession:
+    ///   the same commitment on two confessions would link them. A commitment
+    ///   already held by an unrevealed confession is rejected.
+    ///
+    /// The confession is not added to any author index until the author calls
+    /// `prove_authorship`, so nothing on-chain links it to an address.
+    ///
+    /// Suspension is checked against `submitter` only: the author behind a
+    /// commitment is unknown, so a suspended author can still post through an
+    /// unsuspended submitter. They cannot `prove_authorship` while suspended.
+    ///
+    /// Emits: `("confession_committed", id)` → `(author_commitment, content_hash, timestamp)`
+    pub fn create_confession_committed(
+        env: Env,
+        submitter: Address,
+        author_commitment: BytesN<32>,
+        content_hash: BytesN<32>,
+        timestamp: u64,
+    ) -> u64 {
+        submitter.require_auth();
+
+        emergency_pause::assert_not_paused(&env, PauseScope::Create)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+
+        assert_content_hash_unused(&env, &content_hash);
+        let commitment_key = DataKey::CommitmentIndex(author_commitment.clone());
+        if env.storage().persistent().has(&commitment_key) {
+            panic!("author commitment already in use");
+        }
+        assert_author_not_suspended(&env, &submitter);
+        assert_author_within_rate_limit(&env, &submitter);
+
+        let id = insert_confession(
+            &env,
+            None,
+            Some(author_commitment.clone()),
+            &content_hash,
+            timestamp,
+            None,
+        );
+        env.storage().persistent().set(&commitment_key, &id);
 
-        ConfessionUpdatedEvent {
+        ConfessionCommittedEvent {
             id,
             event_version: events::EVENT_VERSION_V1,
             nonce: bump_confession_event_nonce(&env, id),
             timestamp,
-            old_status,
-            new_status: confession.status,
+            author_commitment,
+            content_hash,
             correlation_id: None,
         }
         .publish(&env);
+
+        id
     }
 
-    /// Replay-protected update_status variant.
-    pub fn update_status_seq(
+    /// Reveal the author of a pseudonymous confession.
+    ///
+    /// `author` must authorize and `sha256(author_xdr || salt)` must equal the
+    /// stored commitment. On success the author is recorded on the confession,
+    /// the confession joins the author's index, and the author can update or
+    /// delete it like any other. Suspended authors cannot prove authorship.
+    ///
+    /// Emits: `("authorship_proven", id)` → `(author, timestamp)`
+    pub fn prove_authorship(env: Env, author: Address, id: u64, salt: BytesN<32>) {
+        author.require_auth();
+
+        emergency_pause::assert_not_paused(&env, PauseScope::Update)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+
+        let mut confession = load_confession(&env, id).expect("confession not found");
+
+        if confession.author.is_some() {
+            panic!("authorship already revealed");
+        }
+        assert_author_not_suspended(&env, &author);
+        let commitment = confession
+            .author_commitment
+            .clone()
+            .expect("confession has no authorship commitment");
+        if compute_author_commitment(&env, &author, &salt) != commitment {
+            panic!("authorship proof does not match commitment");
+        }
+
+        confession.author = Some(author.clone());
+        save_confession(&env, &confession);
+        append_author_confession(&env, &author, id);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CommitmentIndex(commitment));
+
+        AuthorshipProvenEvent {
+            id,
+            event_version: events::EVENT_VERSION_V1,
+            nonce: bump_confession_event_nonce(&env, id),
+            timestamp: env.ledger().timestamp(),
+            author,
+        }
+        .publish(&env);
+    }
+
+    // ─── Scheduled Publication ───
+
+    /// Commit to a confession now and publish it at or after `publish_after`.
+    ///
+    /// - `commitment`: `sha256(content_hash || salt)`; see
+    ///   [`compute_schedule_commitment`]. The content hash stays hidden until
+    ///   publication.
+    ///
+    /// Returns the schedule ID to pass to `publish_scheduled`.
+    ///
+    /// Emits: `("confession_scheduled", schedule_id)` → `(timestamp, author, commitment, publish_after)`
+    pub fn schedule_confession(
+        env: Env,
+        author: Address,
+        commitment: BytesN<32>,
+        publish_after: u64,
+    ) -> u64 {
+        author.require_auth();
+
+        emergency_pause::assert_not_paused(&env, PauseScope::Create)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+        assert_author_not_suspended(&env, &author);
+        assert_not_rotating(&env, &author);
+
+        let now = env.ledger().timestamp();
+        if publish_after <= now {
+            panic!("publish time must be in the future");
+        }
+        let mut pending = author_schedules(&env, &author);
+        if pending.len() >= MAX_PENDING_SCHEDULES_PER_AUTHOR {
+            panic!("too many pending scheduled confessions");
+        }
+
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextScheduleId)
+            .unwrap_or(1u64);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextScheduleId, &(id + 1));
+        env.storage().persistent().set(
+            &DataKey::Scheduled(id),
+            &ScheduledConfession {
+                id,
+                author: author.clone(),
+                commitment: commitment.clone(),
+                publish_after,
+                scheduled_at: now,
+            },
+        );
+        pending.push_back(id);
+        set_author_schedules(&env, &author, &pending);
+
+        ConfessionScheduledEvent {
+            schedule_id: id,
+            event_version: events::EVENT_VERSION_V1,
+            timestamp: now,
+            author,
+            commitment,
+            publish_after,
+        }
+        .publish(&env);
+
+        id
+    }
+
+    /// Reveal a scheduled confession and create it as a normal confession.
+    ///
+    /// The scheduling author must authorize, once the ledger time reaches
+    /// `publish_after`. Publication reveals `content_hash`, and anyone who
+    /// learns it first can claim it with `create_confession`, after which the
+    /// schedule can never publish; so only the author decides when the
+    /// reveal goes out, and `content_hash` and `salt` should not be handed to
+    /// anyone else. The confession is
+    /// attributed to the scheduling author, created at the current ledger
+    /// time in milliseconds (like a client-supplied `timestamp`), and subject to the usual create checks (pause, suspension,
+    /// rate limit, unique content hash).
+    ///
+    /// Emits: `("confession_created", id)` then
+    /// `("scheduled_published", schedule_id)` → `(timestamp, confession_id)`
+    pub fn publish_scheduled(
+        env: Env,
+        schedule_id: u64,
+        content_hash: BytesN<32>,
+        salt: BytesN<32>,
+    ) -> u64 {
+        let key = DataKey::Scheduled(schedule_id);
+        let scheduled: ScheduledConfession = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("scheduled confession not found");
+        scheduled.author.require_auth();
+
+        let now = env.ledger().timestamp();
+        if now < scheduled.publish_after {
+            panic!("scheduled confession is not yet publishable");
+        }
+        if compute_schedule_commitment(&env, &content_hash, &salt) != scheduled.commitment {
+            panic!("content does not match schedule commitment");
+        }
+
+        env.storage().persistent().remove(&key);
+        let mut pending = author_schedules(&env, &scheduled.author);
+        if let Some(pos) = pending.first_index_of(schedule_id) {
+            pending.remove(pos);
+        }
+        set_author_schedules(&env, &scheduled.author, &pending);
+        let id = create_confession_as(&env, scheduled.author, content_hash, now * 1000, None, None);
+
+        ScheduledPublishedEvent {
+            schedule_id,
+            event_version: events::EVENT_VERSION_V1,
+            timestamp: now,
+            confession_id: id,
+        }
+        .publish(&env);
+
+        id
+    }
+
+    /// Pending scheduled confession, or `None` once published.
+    pub fn get_scheduled(env: Env, schedule_id: u64) -> Option<ScheduledConfession> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Scheduled(schedule_id))
+    }
+
+    // ─── Read ───
+
+    /// Get a confession by ID.
+    ///
+    /// Ephemeral confessions past their `expires_at` are reported with
+    /// status `Expired` until they are purged.
+    pub fn get_confession(env: Env, id: u64) -> Confession {
+        let Some(confession) = load_confession(&env, id) else {
+            if env.storage().persistent().has(&DataKey::Tombstone(id)) {
+                panic!("confession has been erased");
+            }
+            panic!("confession not found");
+        };
+        with_read_status(&env, confession)
+    }
+
+    /// Like `get_confession`, but `None` for missing or erased confessions
+    /// instead of panicking, so callers can tell "not found" from other
+    /// failures.
+    pub fn find_confession(env: Env, id: u64) -> Option<Confession> {
+        load_confession(&env, id).map(|confession| with_read_status(&env, confession))
+    }
+
+    /// Get the tombstone of an erased confession, if any.
+    pub fn get_tombstone(env: Env, id: u64) -> Option<ConfessionTombstone> {
+        env.storage().persistent().get(&DataKey::Tombstone(id))
+    }
+
+    /// Get a confession ID by its content hash.
+    pub fn get_by_hash(env: Env, content_hash: BytesN<32>) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HashIndex(content_hash))
+            .expect("no confession with that hash")
+    }
+
+    /// List an author's confession IDs in creation order.
+    ///
+    /// - `cursor`: index position to start from (the `next_cursor` of the
+    ///   previous page). Pass `None` to start from the beginning.
+    /// - `limit`: maximum number of IDs to return (capped at 50).
+    ///
+    /// Only the buckets overlapping the requested range are read. Erased
+    /// confessions are skipped, so a page may hold fewer than `limit` IDs.
+    pub fn list_author_confessions(
+        env: Env,
+        author: Address,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> AuthorPage {
+        let limit = limit.min(MAX_AUTHOR_PAGE_LIMIT) as u64;
+        let total = author_confession_count(&env, &author);
+        let start = cursor.unwrap_or(0).min(total);
+        let end = start.saturating_add(limit).min(total);
+        let bucket_size = AUTHOR_INDEX_BUCKET_SIZE as u64;
+
+        let mut items: Vec<u64> = Vec::new(&env);
+        let mut pos = start;
+        while pos < end {
+            let bucket = (pos / bucket_size) as u32;
+            let ids: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::AuthorBucket(author.clone(), bucket))
+                .unwrap_or_else(|| Vec::new(&env));
+            let bucket_end = ((bucket as u64 + 1) * bucket_size).min(end);
+            while pos < bucket_end {
+                match ids.get((pos % bucket_size) as u32) {
+                    Some(0) | None => {}
+                    Some(id) => items.push_back(id),
+                }
+                pos += 1;
+            }
+        }
+
+        let has_next_page = end < total;
+        AuthorPage {
+            items,
+            has_next_page,
+            next_cursor: if has_next_page { Some(end) } else { None },
+        }
+    }
+
+    /// Get the number of confessions recorded in an author's index,
+    /// excluding erased ones.
+    pub fn get_author_confession_count(env: Env, author: Address) -> u64 {
+        let erased: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuthorErasedCount(author.clone()))
+            .unwrap_or(0);
+        author_confession_count(&env, &author) - erased
+    }
+
+    /// Configure the per-author confession rate limit. Admin only.
+    pub fn configure_author_rate_limit(
         env: Env,
         caller: Address,
-        id: u64,
-        new_status: ConfessionStatus,
-        timestamp: u64,
-        nonce: u64,
-    ) -> Result<(), ReplayError> {
-        consume_nonce(&env, &caller, nonce)?;
-        Self::update_status(env, caller, id, new_status, timestamp);
-        Ok(())
+        max_confessions_per_window: u32,
+        window_seconds: u64,
+    ) {
+        caller.require_auth();
+
+        if !is_registry_admin(&env, &caller) {
+            panic!("unauthorized: only admin can configure rate limits");
+        }
+        if max_confessions_per_window == 0 || window_seconds == 0 {
+            panic!("invalid rate limit configuration");
+        }
+
+        env.storage().instance().set(
+            &DataKey::AuthorRateLimitConfig,
+            &AuthorRateLimitConfig {
+                max_confessions_per_window,
+                window_seconds,
+            },
+        );
+    }
+
+    /// Read the active per-author rate limit.
+    pub fn get_author_rate_limit(env: Env) -> AuthorRateLimitConfig {
+        author_rate_limit_config(&env)
+    }
+
+    // ─── Labels ───
+
+    /// Add `label` to the vocabulary moderators may apply. Admin only.
+    pub fn add_vocabulary_label(env: Env, caller: Address, label: Symbol) {
+        caller.require_auth();
+
+        if !is_registry_admin(&env, &caller) {
+            panic!("unauthorized: only admin can manage label vocabulary");
+        }
+        let mut vocabulary = label_vocabulary(&env);
+        if vocabulary.contains(&label) {
+            panic!("label already in vocabulary");
+        }
+        if vocabulary.len() >= MAX_LABEL_VOCABULARY {
+            panic!("label vocabulary is full");
+        }
+        vocabulary.push_back(label);
+        env.storage()
+            .instance()
+            .set(&DataKey::LabelVocabulary, &vocabulary);
+    }
+
+    /// Retire `label` from the vocabulary. Admin only. Confessions that
+    /// already carry it keep it until a moderator removes it.
+    pub fn remove_vocabulary_label(env: Env, caller: Address, label: Symbol) {
+        caller.require_auth();
+
+        if !is_registry_admin(&env, &caller) {
+            panic!("unauthorized: only admin can manage label vocabulary");
+        }
+        let mut vocabulary = label_vocabulary(&env);
+        let index = vocabulary
+            .first_index_of(&label)
+            .expect("label not in vocabulary");
+        vocabulary.remove(index);
+        env.storage()
+            .instance()
+            .set(&DataKey::LabelVocabulary, &vocabulary);
+    }
+
+    pub fn get_label_vocabulary(env: Env) -> Vec<Symbol> {
+        label_vocabulary(&env)
+    }
+
+    /// Attach a vocabulary label to a confession. Moderators only; at most
+    /// `MAX_LABELS_PER_CONFESSION` labels per confession.
+    ///
+    /// Emits: `("label_added", id)` → `(timestamp, moderator, label)`
+    pub fn add_label(env: Env, moderator: Address, id: u64, label: Symbol) {
+        moderator.require_auth();
+
+        let mut confession = labelable_confession(&env, &moderator, id);
+        if !label_vocabulary(&env).contains(&label) {
+            panic!("label not in vocabulary");
+        }
+        if confession.labels.contains(&label) {
+            panic!("label already applied");
+        }
+        if confession.labels.len() >= MAX_LABELS_PER_CONFESSION {
+            panic!("too many labels on confession");
+        }
+
+        confession.labels.push_back(label.clone());
+        save_confession(&env, &confession);
+
+        LabelAddedEvent {
+            id,
+            event_version: events::EVENT_VERSION_V1,
+            nonce: bump_confession_event_nonce(&env, id),
+            timestamp: env.ledger().timestamp(),
+            moderator,
+            label,
+        }
+        .publish(&env);
+    }
+
+    /// Remove a label from a confession. Moderators only.
+    ///
+    /// Emits: `("label_removed", id)` → `(timestamp, moderator, label)`
+    pub fn remove_label(env: Env, moderator: Address, id: u64, label: Symbol) {
+        moderator.require_auth();
+
+        let mut confession = labelable_confession(&env, &moderator, id);
+        let index = confession
+            .labels
+            .first_index_of(&label)
+            .expect("label not applied");
+        confession.labels.remove(index);
+        save_confession(&env, &confession);
+
+        LabelRemovedEvent {
+            id,
+            event_version: events::EVENT_VERSION_V1,
+            nonce: bump_confession_event_nonce(&env, id),
+            timestamp: env.ledger().timestamp(),
+            moderator,
+            label,
+        }
+        .publish(&env);
+    }
+
+    // ─── Suspensions ───
+
+    /// Block `author` from creating confessions until ledger time `until`.
+    ///
+    /// Moderators only. Replaces any existing suspension, so it can also be
+    /// used to extend or shorten one. Moderators cannot be suspended.
+    ///
+    /// Emits: `("author_suspended", author)` → `(timestamp, moderator, until, reason_code)`
+    pub fn suspend_author(
+        env: Env,
+        moderator: Address,
+        author: Address,
+        until: u64,
+        reason_code: u32,
+    ) {
+        moderator.require_auth();
+
+        if !is_moderator(&env, &moderator) {
+            panic!("unauthorized: only moderator can suspend authors");
+        }
+        if is_moderator(&env, &author) {
+            panic!("cannot suspend a moderator");
+        }
+        let now = env.ledger().timestamp();
+        if until <= now {
+            panic!("suspension must end in the future");
+        }
+
+        env.storage().persistent().set(
+            &DataKey::Suspension(author.clone()),
+            &AuthorSuspension {
+                moderator: moderator.clone(),
+                suspended_at: now,
+                until,
+                reason_code,
+            },
+        );
+
+        AuthorSuspendedEvent {
+            author,
+            event_version: events::EVENT_VERSION_V1,
+            timestamp: now,
+            moderator,
+            until,
+            reason_code,
+        }
+        .publish(&env);
+    }
+
+    /// End an active suspension early. Moderators only.
+    ///
+    /// Emits: `("suspension_lifted", author)` → `(timestamp, moderator)`
+    pub fn lift_suspension(env: Env, moderator: Address, author: Address) {
+        moderator.require_auth();
+
+        if !is_moderator(&env, &moderator) {
+            panic!("unauthorized: only moderator can lift suspensions");
+        }
+        if active_suspension(&env, &author).is_none() {
+            panic!("author is not suspended");
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Suspension(author.clone()));
+
+        SuspensionLiftedEvent {
+            author,
+            event_version: events::EVENT_VERSION_V1,
+            timestamp: env.ledger().timestamp(),
+            moderator,
+        }
+        .publish(&env);
+    }
+
+    /// The author's active suspension, or `None` once it has lapsed.
+    pub fn get_suspension(env: Env, author: Address) -> Option<AuthorSuspension> {
+        active_suspension(&env, &author)
+    }
+
+    // ─── Author Rotation ───
+
+    /// Move every confession and pending scheduled confession of `old` to
+    /// `new`. Both keys must authorize.
+    ///
+    /// Rewrites `author` on each stored record and moves the author index;
+    /// `new` keeps any confessions it already had. At most
+    /// `MAX_ROTATION_INDEX_LEN` index slots move per call: returns `false`
+    /// when more remain, to be moved with `continue_rotation`. Suspended
+    /// keys, keys already in a rotation, and rotations that would leave
+    /// `new` with more than `MAX_PENDING_SCHEDULES_PER_AUTHOR` pending
+    /// schedules are rejected.
+    ///
+    /// Emits on completion: `("author_rotated", old, new)` → `(timestamp, moved, None)`
+    pub fn rotate_author(env: Env, old: Address, new: Address) -> bool {
+        old.require_auth();
+        new.require_auth();
+        start_rotation(&env, old, new, None)
+    }
+
+    /// Move the next batch of an unfinished rotation away from `old`.
+    /// Permissionless: the rotation was authorized when it started. Returns
+    /// `true` once the rotation is complete.
+    pub fn continue_rotation(env: Env, old: Address) -> bool {
+        emergency_pause::assert_not_paused(&env, PauseScope::Update)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+
+        let rotation: AuthorRotation = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Rotation(old.clone()))
+            .expect("no rotation in progress");
+        advance_rotation(&env, old, rotation)
+    }
+
+    /// Progress of an unfinished rotation away from `old`.
+    pub fn get_rotation(env: Env, old: Address) -> Option<AuthorRotation> {
+        env.storage().persistent().get(&DataKey::Rotation(old))
+    }
+
+    /// Recovery path for a lost key: an owner or admin approves moving
+    /// `old`'s confessions to `new`, which must authorize. The rotation can
+    /// only start with `execute_recovery` after
+    /// `AUTHOR_RECOVERY_DELAY_SECONDS`, and `old` can cancel it until then.
+    ///
+    /// Emits: `("recovery_requested", old, new)` → `(timestamp, approved_by, executable_at)`
+    pub fn recover_author(env: Env, admin: Address, old: Address, new: Address) {
+        admin.require_auth();
+        new.require_auth();
+
+        if !is_registry_admin(&env, &admin) {
+            panic!("unauthorized: only owner or admin can approve recovery");
+        }
+        if old == new {
+            panic!("new author must differ from old author");
+        }
+        let key = DataKey::PendingRecovery(old.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("recovery already pending");
+        }
+
+        let now = env.ledger().timestamp();
+        let executable_at = now + AUTHOR_RECOVERY_DELAY_SECONDS;
+        env.storage().persistent().set(
+            &key,
+            &PendingRecovery {
+                new_author: new.clone(),
+                approved_by: admin.clone(),
+                requested_at: now,
+                executable_at,
+            },
+        );
+
+        RecoveryRequestedEvent {
+            old_author: old,
+            new_author: new,
+            event_version: events::EVENT_VERSION_V1,
+            timestamp: now,
+            approved_by: admin,
+            executable_at,
+        }
+        .publish(&env);
+    }
+
+    /// Cancel a pending recovery of `old`, which must authorize.
+    ///
+    /// Emits: `("recovery_cancelled", old)` → `(timestamp)`
+    pub fn cancel_recovery(env: Env, old: Address) {
+        old.require_auth();
+
+        let key = DataKey::PendingRecovery(old.clone());
+        if !env.storage().persistent().has(&key) {
+            panic!("no pending recovery");
+        }
+        env.storage().persistent().remove(&key);
+
+        RecoveryCancelledEvent {
+            old_author: old,
+            event_version: events::EVENT_VERSION_V1,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+    }
+
+    /// Start the rotation of a recovery whose delay has passed.
+    /// Permissionless. Returns `true` once the rotation is complete; see
+    /// `continue_rotation` otherwise.
+    ///
+    /// Emits on completion: `("author_rotated", old, new)` → `(timestamp, moved, Some(approved_by))`
+    pub fn execute_recovery(env: Env, old: Address) -> bool {
+        let key = DataKey::PendingRecovery(old.clone());
+        let pending: PendingRecovery = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("no pending recovery");
+        if env.ledger().timestamp() < pending.executable_at {
+            panic!("recovery delay has not elapsed");
+        }
+
+        env.storage().persistent().remove(&key);
+        start_rotation(&env, old, pending.new_author, Some(pending.approved_by))
+    }
+
+    /// Pending recovery of `old`, or `None`.
+    pub fn get_pending_recovery(env: Env, old: Address) -> Option<PendingRecovery> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingRecovery(old))
+    }
+
+    /// List confessions with cursor-based pagination.
+    ///
+    /// - `cursor`: exclusive lower bound (last seen ID). Pass `None` to start from the beginning.
+    /// - `limit`: maximum number of items to return (capped at 50).
+    ///
+    /// Returns a `Page<Confession>` with `has_next_page` and `next_cursor` so callers
+    /// can detect terminal pages without guessing.
+    pub fn list_confessions(env: Env, cursor: Option<u64>, limit: u32) -> Page {
+        let limit = limit.min(50) as u64;
+        let start = cursor.unwrap_or(0) + 1;
+        let total: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextId)
+            .unwrap_or(1u64)
+            .saturating_sub(1);
+
+        let mut items: Vec<Confession> = Vec::new(&env);
+        let mut id = start;
+        // Fetch up to limit+1 to detect whether a next page exists.
+        while id <= total && items.len() as u64 <= limit {
+            if let Some(c) = load_confession(&env, id) {
+                items.push_back(with_read_status(&env, c));
+            }
+            id += 1;
+        }
+
+        let has_next_page = items.len() as u64 > limit;
+        if has_next_page {
+            items.pop_back();
+        }
+
+        let next_cursor = if has_next_page {
+            items.last().map(|c| c.id)
+        } else {
+            None
+        };
+
+        Page {
+            items,
+            has_next_page,
+            next_cursor,
+        }
+    }
+
+    /// Get the total number of confessions created.
+    pub fn get_total_count(env: Env) -> u64 {
+        let next_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextId)
+            .unwrap_or(1u64);
+        next_id - 1
+    }
+
+    // ─── Checkpoints ───
+
+    /// Live counters, latest nonces and their digest, for reconciliation.
+    pub fn get_checkpoint(env: Env) -> checkpoint::CheckpointSummary {
+        checkpoint::get_checkpoint_summary(&env)
+    }
+
+    /// Page through stored confessions or erasure tombstones by ID.
+    ///
+    /// Examines up to `limit` IDs (max 50) after `cursor`; continue with
+    /// `next_cursor` until it is `None`.
+    pub fn get_snapshot_page(
+        env: Env,
+        kind: checkpoint::SnapshotKind,
+        cursor: Option<u64>,
+        limit: u32,
+    ) -> checkpoint::SnapshotPage {
+        checkpoint::get_snapshot_page(&env, kind, cursor, limit)
+    }
+
+    /// Recount the checkpoint counters from storage, for deployments upgraded
+    /// from a version without them. Owner or admin only.
+    ///
+    /// Each call examines up to `limit` IDs (max 50) and returns the next ID
+    /// to examine; call again until it returns `None`, at which point the
+    /// recount replaces the live counters. Writes made meanwhile are carried
+    /// over. `nonces_consumed` cannot be recounted and keeps its live value.
+    pub fn rebuild_checkpoint_counters(env: Env, caller: Address, limit: u32) -> Option<u64> {
+        caller.require_auth();
+        if !is_registry_admin(&env, &caller) {
+            panic!("unauthorized: only admin can rebuild checkpoint counters");
+        }
+        if limit == 0 {
+            panic!("limit must be positive");
+        }
+        checkpoint::rebuild_counters(&env, limit)
+    }
+
+    /// Return the lowest unused nonce for a caller in sequenced mutation methods.
+    pub fn get_expected_nonce(env: Env, caller: Address) -> u64 {
+        expected_nonce(&env, &caller)
+    }
+
+    /// Return the caller's replay window. Any nonce in
+    /// `[base, base + NONCE_WINDOW_SIZE)` whose bit in `used` is clear may be
+    /// submitted, in any order.
+    pub fn get_nonce_window(env: Env, caller: Address) -> NonceWindow {
+        nonce_window(&env, &caller)
+    }
+
+    /// Replay-protected create_confession variant.
+    ///
+    /// `nonce` must be an unused nonce inside the caller's window; the call is
+    /// rejected once the ledger timestamp passes `expires_at`, when set.
+    pub fn create_confession_seq(
+        env: Env,
+        author: Address,
+        content_hash: BytesN<32>,
+        timestamp: u64,
+        nonce: u64,
+        expires_at: Option<u64>,
+        correlation_id: Option<Symbol>,
+    ) -> Result<u64, ReplayError> {
+        consume_nonce(&env, &author, nonce, expires_at)?;
+        Ok(Self::create_confession(
+            env,
+            author,
+            content_hash,
+            timestamp,
+            correlation_id,
+        ))
+    }
+
+    /// Replay-protected create_ephemeral_confession variant. The confession
+    /// expires at `confession_expires_at`; `expires_at` bounds the request as
+    /// in `create_confession_seq`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_ephemeral_seq(
+        env: Env,
+        author: Address,
+        content_hash: BytesN<32>,
+        timestamp: u64,
+        confession_expires_at: u64,
+        nonce: u64,
+        expires_at: Option<u64>,
+    ) -> Result<u64, ReplayError> {
+        consume_nonce(&env, &author, nonce, expires_at)?;
+        Ok(Self::create_ephemeral_confession(
+            env,
+            author,
+            content_hash,
+            timestamp,
+            confession_expires_at,
+        ))
+    }
+
+    /// Relayed create_ephemeral_confession; the signed method is
+    /// `"create_ephemeral_relayed"` with args
+    /// `(content_hash, timestamp, expires_at)`.
+    pub fn create_ephemeral_relayed(
+        env: Env,
+        auth: RelayAuth,
+        content_hash: BytesN<32>,
+        timestamp: u64,
+        expires_at: u64,
+    ) -> Result<u64, ReplayError> {
+        let args = vec![
+            &env,
+            content_hash.clone().into_val(&env),
+            timestamp.into_val(&env),
+            expires_at.into_val(&env),
+        ];
+        let author = authenticate_relayed(&env, &auth, "create_ephemeral_relayed", args)?;
+        Ok(create_confession_as(
+            &env,
+            author,
+            content_hash,
+            timestamp,
+            Some(expires_at),
+            None,
+        ))
+    }
+
+    /// Relayed create_confession: a third party submits and pays for the
+    /// transaction on behalf of the holder of `auth.public_key`.
+    ///
+    /// The signed method is `"create_confession_relayed"` with args
+    /// `(content_hash, timestamp)`. The author is the account address of the
+    /// public key, and `auth.nonce` is consumed from that address's
+    /// `CallerNonce` sequence. Requests are rejected after `auth.expiry`.
+    pub fn create_confession_relayed(
+        env: Env,
+        auth: RelayAuth,
+        content_hash: BytesN<32>,
+        timestamp: u64,
+    ) -> Result<u64, ReplayError> {
+        let args = vec![
+            &env,
+            content_hash.clone().into_val(&env),
+            timestamp.into_val(&env),
+        ];
+        let author = authenticate_relayed(&env, &auth, "create_confession_relayed", args)?;
+        Ok(create_confession_as(
+            &env,
+            author,
+            content_hash,
+            timestamp,
+            None,
+            None,
+        ))
+    }
+
+    // ─── Update Status ───
+
+    /// Update the status of a confession.
+    ///
+    /// Only the author or a moderator (owner, admin or operator) can change
+    /// status. Authors of
+    /// pseudonymous confessions must call `prove_authorship` first.
+    ///
+    /// Emits: `("confession_updated", id)` → `(old_status, new_status, timestamp, correlation_id)`
+    pub fn update_status(
+        env: Env,
+        caller: Address,
+        id: u64,
+        new_status: ConfessionStatus,
+        timestamp: u64,
+        correlation_id: Option<Symbol>,
+    ) {
+        caller.require_auth();
+
+        update_status_as(&env, caller, id, new_status, timestamp, correlation_id);
+    }
+
+    /// Replay-protected update_status variant.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_status_seq(
+        env: Env,
+        caller: Address,
+        id: u64,
+        new_status: ConfessionStatus,
+        timestamp: u64,
+        nonce: u64,
+        expires_at: Option<u64>,
+        correlation_id: Option<Symbol>,
+    ) -> Result<(), ReplayError> {
+        consume_nonce(&env, &caller, nonce, expires_at)?;
+        Self::update_status(env, caller, id, new_status, timestamp, correlation_id);
+        Ok(())
+    }
+
+    /// Relayed update_status; signed args are `(id, new_status, timestamp)`.
+    pub fn update_status_relayed(
+        env: Env,
+        auth: RelayAuth,
+        id: u64,
+        new_status: ConfessionStatus,
+        timestamp: u64,
+    ) -> Result<(), ReplayError> {
+        let args = vec![
+            &env,
+            id.into_val(&env),
+            new_status.clone().into_val(&env),
+            timestamp.into_val(&env),
+        ];
+        let caller = authenticate_relayed(&env, &auth, "update_status_relayed", args)?;
+        update_status_as(&env, caller, id, new_status, timestamp, None);
+        Ok(())
+    }
+
+    // ─── Delete ───
+
+    /// Soft-delete a confession (set status to Deleted).
+    ///
+    /// Only the author or a moderator can delete. Authors of pseudonymous
+    /// confessions must call `prove_authorship` first.
+    ///
+    /// Emits: `("confession_deleted", id)` → `(caller, timestamp, correlation_id)`
+    pub fn delete_confession(
+        env: Env,
+        caller: Address,
+        id: u64,
+        timestamp: u64,
+        correlation_id: Option<Symbol>,
+    ) {
+        caller.require_auth();
+
+        delete_confession_as(&env, caller, id, timestamp, correlation_id);
+    }
+
+    /// Replay-protected delete_confession variant.
+    pub fn delete_confession_seq(
+        env: Env,
+        caller: Address,
+        id: u64,
+        timestamp: u64,
+        nonce: u64,
+        expires_at: Option<u64>,
+        correlation_id: Option<Symbol>,
+    ) -> Result<(), ReplayError> {
+        consume_nonce(&env, &caller, nonce, expires_at)?;
+        Self::delete_confession(env, caller, id, timestamp, correlation_id);
+        Ok(())
+    }
+
+    // ─── Erasure ───
+
+    /// Hard-erase a confession (right to erasure).
+    ///
+    /// Only the author, owner or an admin can erase. Removes the record body, its
+    /// `HashIndex` entry and its author-index slot, leaving a
+    /// `ConfessionTombstone` with just the id and erasure time. Unlike
+    /// `delete_confession`, neither the author nor the content hash remain
+    /// readable afterwards.
+    ///
+    /// Emits: `("confession_erased", id)` → `(timestamp)`
+    pub fn erase_confession(env: Env, caller: Address, id: u64) {
+        caller.require_auth();
+
+        emergency_pause::assert_not_paused(&env, PauseScope::Delete)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+
+        let confession = load_confession(&env, id).expect("confession not found");
+
+        if confession.author.as_ref() != Some(&caller) && !is_registry_admin(&env, &caller) {
+            panic!("unauthorized: only author or admin can erase");
+        }
+
+        remove_confession(&env, id);
+        env.storage()
+            .instance()
+            .remove(&DataKey::HashIndex(confession.content_hash.clone()));
+        if let Some(commitment) = confession.author_commitment.clone() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::CommitmentIndex(commitment));
+        }
+        checkpoint::record_erased(&env, &confession);
+        if let Some(author) = confession.author {
+            remove_author_confession(&env, &author, id);
+        }
+
+        let erased_at = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &DataKey::Tombstone(id),
+            &ConfessionTombstone { id, erased_at },
+        );
+
+        ConfessionErasedEvent {
+            id,
+            event_version: events::EVENT_VERSION_V1,
+            nonce: bump_confession_event_nonce(&env, id),
+            timestamp: erased_at,
+        }
+        .publish(&env);
+    }
+
+    // ─── Expiry ───
+
+    /// Remove expired ephemeral confessions. Permissionless and bounded.
+    ///
+    /// Examines at most `limit` (1 to 50) IDs after `cursor` and deletes the
+    /// record and `HashIndex` entry of each expired one, clears its author
+    /// index slot, and emits `("confession_purged", id)`. Ephemeral records
+    /// live in persistent storage until `EPHEMERAL_PURGE_GRACE_LEDGERS` past
+    /// expiry; purge them before then.
+    pub fn purge_expired(env: Env, cursor: Option<u64>, limit: u32) -> PurgeResult {
+        emergency_pause::assert_not_paused(&env, PauseScope::Delete)
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+        if limit == 0 {
+            panic!("limit must be positive");
+        }
+
+        let last_id = Self::get_total_count(env.clone());
+        let Some(start) = cursor.map_or(Some(1), |c| c.checked_add(1)) else {
+            return PurgeResult {
+                purged: 0,
+                next_cursor: None,
+            };
+        };
+        let end = start
+            .saturating_add(limit.min(MAX_PURGE_LIMIT) as u64 - 1)
+            .min(last_id);
+
+        let mut purged = 0u32;
+        let mut id = start;
+        while id <= end {
+            if let Some(confession) = load_confession(&env, id) {
+                if is_expired(&env, &confession) {
+                    remove_confession(&env, id);
+                    env.storage()
+                        .instance()
+                        .remove(&DataKey::HashIndex(confession.content_hash.clone()));
+                    if let Some(author) = &confession.author {
+                        remove_author_confession(&env, author, id);
+                    }
+                    checkpoint::record_purged(&env, &confession);
+
+                    ConfessionPurgedEvent {
+                        id,
+                        event_version: events::EVENT_VERSION_V1,
+                        nonce: bump_confession_event_nonce(&env, id),
+                        timestamp: env.ledger().timestamp(),
+                        expires_at: confession.expires_at.unwrap_or_default(),
+                        content_hash: confession.content_hash,
+                    }
+                    .publish(&env);
+                    purged += 1;
+                }
+            }
+            id += 1;
+        }
+
+        PurgeResult {
+            purged,
+            next_cursor: if end < last_id { Some(end) } else { None },
+        }
+    }
+
+    /// Relayed delete_confession; signed args are `(id, timestamp)`.
+    pub fn delete_confession_relayed(
+        env: Env,
+        auth: RelayAuth,
+        id: u64,
+        timestamp: u64,
+    ) -> Result<(), ReplayError> {
+        let args = vec![&env, id.into_val(&env), timestamp.into_val(&env)];
+        let caller = authenticate_relayed(&env, &auth, "delete_confession_relayed", args)?;
+        delete_confession_as(&env, caller, id, timestamp, None);
+        Ok(())
+    }
+}
+
+// ─── Tests ───
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::{Address as _, Events as _, Ledger},
+        Address, BytesN, Env, TryFromVal,
+    };
+
+    fn setup() -> (Env, ConfessionRegistryClient<'static>, Address, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ConfessionRegistry, ());
+        let client = ConfessionRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let author = Address::generate(&env);
+
+        client.initialize(&admin);
+
+        (env, client, admin, author)
+    }
+
+    fn sample_hash(env: &Env, value: u8) -> BytesN<32> {
+        let mut bytes: [u8; 32] = [0; 32];
+        bytes[0] = value;
+        BytesN::from_array(env, &bytes)
+    }
+
+    #[test]
+    fn test_create_and_read_confession() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 1);
+        let ts: u64 = 1_700_000_000_000;
+
+        let id = client.create_confession(&author, &hash, &ts, &None);
+        assert_eq!(id, 1);
+
+        let conf = client.get_confession(&id);
+        assert_eq!(conf.id, 1);
+        assert_eq!(conf.author, Some(author));
+        assert_eq!(conf.author_commitment, None);
+        assert_eq!(conf.content_hash, hash);
+        assert_eq!(conf.created_at, ts);
+        assert_eq!(conf.updated_at, 0);
+        assert_eq!(conf.status, ConfessionStatus::Active);
+    }
+
+    #[test]
+    fn test_get_by_hash() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 2);
+        let ts: u64 = 1_700_000_000_001;
+
+        let id = client.create_confession(&author, &hash, &ts, &None);
+        let found_id = client.get_by_hash(&hash);
+        assert_eq!(id, found_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "confession with this content hash already exists")]
+    fn test_duplicate_content_hash_rejected() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 3);
+
+        client.create_confession(&author, &hash, &1_700_000_000_000, &None);
+        client.create_confession(&author, &hash, &1_700_000_000_001, &None); // panic
+    }
+
+    #[test]
+    fn test_author_confessions_index() {
+        let (env, client, _admin, author) = setup();
+        let hash1 = sample_hash(&env, 10);
+        let hash2 = sample_hash(&env, 11);
+
+        client.create_confession(&author, &hash1, &1_700_000_000_001, &None);
+        client.create_confession(&author, &hash2, &1_700_000_000_002, &None);
+
+        let page = client.list_author_confessions(&author, &None, &10);
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items.get(0).unwrap(), 1);
+        assert_eq!(page.items.get(1).unwrap(), 2);
+        assert!(!page.has_next_page);
+
+        let first = client.list_author_confessions(&author, &None, &1);
+        assert_eq!(first.items.len(), 1);
+        assert!(first.has_next_page);
+        assert_eq!(first.next_cursor, Some(1));
+    }
+
+    #[test]
+    fn test_total_count() {
+        let (env, client, _admin, author) = setup();
+
+        assert_eq!(client.get_total_count(), 0);
+
+        client.create_confession(&author, &sample_hash(&env, 20), &1_000, &None);
+        assert_eq!(client.get_total_count(), 1);
+
+        client.create_confession(&author, &sample_hash(&env, 21), &2_000, &None);
+        assert_eq!(client.get_total_count(), 2);
+    }
+
+    #[test]
+    fn test_update_status_by_author() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 30);
+
+        let id = client.create_confession(&author, &hash, &1_000, &None);
+        client.update_status(&author, &id, &ConfessionStatus::Flagged, &2_000, &None);
+
+        let conf = client.get_confession(&id);
+        assert_eq!(conf.status, ConfessionStatus::Flagged);
+        assert_eq!(conf.updated_at, 2_000);
+    }
+
+    #[test]
+    fn test_update_status_by_admin() {
+        let (env, client, admin, author) = setup();
+        let hash = sample_hash(&env, 31);
+
+        let id = client.create_confession(&author, &hash, &1_000, &None);
+        client.update_status(&admin, &id, &ConfessionStatus::Flagged, &2_000, &None);
+
+        let conf = client.get_confession(&id);
+        assert_eq!(conf.status, ConfessionStatus::Flagged);
+    }
+
+    #[test]
+    #[should_panic(expected = "unauthorized")]
+    fn test_update_status_by_unauthorized_user() {
+        let (env, client, _admin, author) = setup();
+        let outsider = Address::generate(&env);
+        let hash = sample_hash(&env, 32);
+
+        let id = client.create_confession(&author, &hash, &1_000, &None);
+        client.update_status(&outsider, &id, &ConfessionStatus::Flagged, &2_000, &None);
+        // panic
+    }
+
+    #[test]
+    fn test_delete_confession() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 40);
+
+        let id = client.create_confession(&author, &hash, &1_000, &None);
+        client.delete_confession(&author, &id, &3_000, &None);
+
+        let conf = client.get_confession(&id);
+        assert_eq!(conf.status, ConfessionStatus::Deleted);
+        assert_eq!(conf.updated_at, 3_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "unauthorized")]
+    fn test_delete_by_unauthorized_user() {
+        let (env, client, _admin, author) = setup();
+        let outsider = Address::generate(&env);
+        let hash = sample_hash(&env, 41);
+
+        let id = client.create_confession(&author, &hash, &1_000, &None);
+        client.delete_confession(&outsider, &id, &2_000, &None); // panic
+    }
+
+    #[test]
+    #[should_panic(expected = "confession not found")]
+    fn test_get_nonexistent_confession() {
+        let (_env, client, _admin, _author) = setup();
+        client.get_confession(&999);
+    }
+
+    #[test]
+    fn test_governance_flow() {
+        let (env, client, admin, _author) = setup();
+
+        let new_admin = Address::generate(&env);
+        let action = governance::model::CriticalAction::GrantAdmin(new_admin.clone());
+
+        // Propose
+        let id = client.gov_propose(&admin, &action);
+
+        // Approve (default quorum is 1)
+        client.gov_approve(&admin, &id);
+
+        // Execute
+        client.gov_execute(&admin, &id);
+
+        // Verify
+        let is_adm = env.as_contract(&client.address, || {
+            access_control::is_admin(&env, &new_admin)
+        });
+        assert!(is_adm);
+    }
+
+    #[test]
+    fn test_governance_quorum() {
+        let (env, client, admin, _author) = setup();
+        let admin2 = Address::generate(&env);
+
+        // Grant second admin first
+        let grant_id = client.gov_propose(
+            &admin,
+            &governance::model::CriticalAction::GrantAdmin(admin2.clone()),
+        );
+        client.gov_approve(&admin, &grant_id);
+        client.gov_execute(&admin, &grant_id);
+
+        // Set quorum to 2
+        client.set_quorum(&2);
+
+        let new_admin = Address::generate(&env);
+        let action = governance::model::CriticalAction::GrantAdmin(new_admin.clone());
+
+        let id = client.gov_propose(&admin, &action);
+
+        // Approve 1/2
+        client.gov_approve(&admin, &id);
+
+        // Execute (should fail)
+        let res = client.try_gov_execute(&admin, &id);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_execute_without_quorum() {
+        let (_env, client, admin, _author) = setup();
+        client.set_quorum(&2);
+
+        let id = client.gov_propose(&admin, &governance::model::CriticalAction::Pause);
+        client.gov_approve(&admin, &id);
+        let result = client.try_gov_execute(&admin, &id);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_governance_revoke() {
+        let (_env, client, admin, _author) = setup();
+        let id = client.gov_propose(&admin, &governance::model::CriticalAction::Pause);
+
+        client.gov_approve(&admin, &id);
+        client.gov_revoke(&admin, &id);
+
+        // Try to execute (should fail since 0/1 approvals now)
+        let res = client.try_gov_execute(&admin, &id);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_pause_via_governance() {
+        let (env, client, admin, author) = setup();
+        let hash = sample_hash(&env, 50);
+
+        // Propose Pause
+        let id = client.gov_propose(&admin, &governance::model::CriticalAction::Pause);
+        client.gov_approve(&admin, &id);
+        client.gov_execute(&admin, &id);
+
+        // Try to create confession (should fail)
+        let res = client.try_create_confession(&author, &hash, &1_000, &None);
+        assert!(res.is_err());
+
+        // Unpause
+        let id2 = client.gov_propose(&admin, &governance::model::CriticalAction::Unpause);
+        client.gov_approve(&admin, &id2);
+        client.gov_execute(&admin, &id2);
+
+        // Try to create confession (should succeed)
+        client.create_confession(&author, &hash, &2_000, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "already initialized")]
+    fn test_double_initialization() {
+        let (env, client, _admin, _author) = setup();
+        let another = Address::generate(&env);
+        client.initialize(&another); // should panic
+    }
+
+    fn wide_hash(env: &Env, value: u32) -> BytesN<32> {
+        let mut bytes: [u8; 32] = [0; 32];
+        bytes[..4].copy_from_slice(&value.to_be_bytes());
+        bytes[31] = 0xAA;
+        BytesN::from_array(env, &bytes)
+    }
+
+    #[test]
+    fn author_rate_limit_exact_limit_succeeds() {
+        let (env, client, _admin, author) = setup();
+
+        for seed in 0..DEFAULT_MAX_CONFESSIONS_PER_WINDOW {
+            let hash = sample_hash(&env, seed as u8);
+            let id = client.create_confession(&author, &hash, &(1_000 + seed as u64), &None);
+            assert_eq!(id, seed as u64 + 1);
+        }
+
+        assert_eq!(
+            client.get_author_confession_count(&author),
+            DEFAULT_MAX_CONFESSIONS_PER_WINDOW as u64
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "author rate limit exceeded")]
+    fn author_rate_limit_plus_one_rejected() {
+        let (env, client, _admin, author) = setup();
+
+        for seed in 0..DEFAULT_MAX_CONFESSIONS_PER_WINDOW {
+            let hash = sample_hash(&env, seed as u8);
+            client.create_confession(&author, &hash, &(1_000 + seed as u64), &None);
+        }
+
+        let hash = sample_hash(&env, DEFAULT_MAX_CONFESSIONS_PER_WINDOW as u8);
+        let _ = client.create_confession(&author, &hash, &9_999, &None);
+    }
+
+    #[test]
+    fn author_rate_limit_resets_after_window() {
+        let (env, client, admin, author) = setup();
+        client.configure_author_rate_limit(&admin, &2, &60);
+
+        client.create_confession(&author, &sample_hash(&env, 1), &1_000, &None);
+        client.create_confession(&author, &sample_hash(&env, 2), &1_001, &None);
+        assert!(client
+            .try_create_confession(&author, &sample_hash(&env, 3), &1_002, &None)
+            .is_err());
+
+        env.ledger().with_mut(|l| l.timestamp += 60);
+        client.create_confession(&author, &sample_hash(&env, 3), &1_003, &None);
+        assert_eq!(client.get_author_confession_count(&author), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "unauthorized")]
+    fn configure_author_rate_limit_requires_admin() {
+        let (_env, client, _admin, author) = setup();
+        client.configure_author_rate_limit(&author, &10, &60);
+    }
+
+    #[test]
+    fn author_index_pages_across_buckets() {
+        let (env, client, admin, author) = setup();
+        let total = AUTHOR_INDEX_BUCKET_SIZE * 2 + 5;
+        client.configure_author_rate_limit(&admin, &total, &60);
+
+        for seed in 0..total {
+            client.create_confession(&author, &wide_hash(&env, seed), &(seed as u64), &None);
+        }
+        assert_eq!(client.get_author_confession_count(&author), total as u64);
+
+        let mut cursor = None;
+        let mut expected_id = 1u64;
+        loop {
+            let page = client.list_author_confessions(&author, &cursor, &MAX_AUTHOR_PAGE_LIMIT);
+            for id in page.items.iter() {
+                assert_eq!(id, expected_id);
+                expected_id += 1;
+            }
+            if !page.has_next_page {
+                assert_eq!(page.next_cursor, None);
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+        assert_eq!(expected_id, total as u64 + 1);
+    }
+
+    #[test]
+    fn author_index_cursor_past_end_is_empty_terminal_page() {
+        let (env, client, _admin, author) = setup();
+        client.create_confession(&author, &sample_hash(&env, 1), &1_000, &None);
+
+        let page = client.list_author_confessions(&author, &Some(10), &5);
+        assert_eq!(page.items.len(), 0);
+        assert!(!page.has_next_page);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    fn salt(env: &Env, value: u8) -> BytesN<32> {
+        BytesN::from_array(env, &[value; 32])
+    }
+
+    #[test]
+    fn committed_confession_hides_author_until_proven() {
+        let (env, client, _admin, author) = setup();
+        let relayer = Address::generate(&env);
+        let commitment = compute_author_commitment(&env, &author, &salt(&env, 7));
+
+        let id = client.create_confession_committed(
+            &relayer,
+            &commitment,
+            &sample_hash(&env, 60),
+            &1_000,
+        );
+
+        let conf = client.get_confession(&id);
+        assert_eq!(conf.author, None);
+        assert_eq!(conf.author_commitment, Some(commitment));
+        assert_eq!(client.get_author_confession_count(&author), 0);
+        assert_eq!(client.get_author_confession_count(&relayer), 0);
+
+        client.prove_authorship(&author, &id, &salt(&env, 7));
+
+        let conf = client.get_confession(&id);
+        assert_eq!(conf.author, Some(author.clone()));
+        let ids = client.list_author_confessions(&author, &None, &10).items;
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids.get(0).unwrap(), id);
+    }
+
+    #[test]
+    #[should_panic(expected = "authorship proof does not match commitment")]
+    fn prove_authorship_rejects_wrong_salt() {
+        let (env, client, _admin, author) = setup();
+        let relayer = Address::generate(&env);
+        let commitment = compute_author_commitment(&env, &author, &salt(&env, 7));
+        let id = client.create_confession_committed(
+            &relayer,
+            &commitment,
+            &sample_hash(&env, 61),
+            &1_000,
+        );
+
+        client.prove_authorship(&author, &id, &salt(&env, 8));
+    }
+
+    #[test]
+    #[should_panic(expected = "authorship proof does not match commitment")]
+    fn prove_authorship_rejects_other_address() {
+        let (env, client, _admin, author) = setup();
+        let relayer = Address::generate(&env);
+        let impostor = Address::generate(&env);
+        let commitment = compute_author_commitment(&env, &author, &salt(&env, 7));
+        let id = client.create_confession_committed(
+            &relayer,
+            &commitment,
+            &sample_hash(&env, 62),
+            &1_000,
+        );
+
+        client.prove_authorship(&impostor, &id, &salt(&env, 7));
+    }
+
+    #[test]
+    #[should_panic(expected = "authorship already revealed")]
+    fn prove_authorship_rejects_plain_confession() {
+        let (env, client, _admin, author) = setup();
+        let id = client.create_confession(&author, &sample_hash(&env, 63), &1_000, &None);
+
+        client.prove_authorship(&author, &id, &salt(&env, 7));
+    }
+
+    #[test]
+    fn scheduled_confession_publishes_after_deadline() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 64);
+        let commitment = compute_schedule_commitment(&env, &hash, &salt(&env, 9));
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+        let schedule_id = client.schedule_confession(&author, &commitment, &5_000);
+        assert_eq!(client.get_total_count(), 0);
+        assert_eq!(
+            client.get_scheduled(&schedule_id).unwrap().publish_after,
+            5_000
+        );
+
+        env.ledger().with_mut(|l| l.timestamp = 5_000);
+        let id = client.publish_scheduled(&schedule_id, &hash, &salt(&env, 9));
+
+        let conf = client.get_confession(&id);
+        assert_eq!(conf.author, Some(author.clone()));
+        assert_eq!(conf.content_hash, hash);
+        assert_eq!(conf.created_at, 5_000_000);
+        assert_eq!(client.get_scheduled(&schedule_id), None);
+        assert_eq!(
+            client
+                .list_author_confessions(&author, &None, &10)
+                .items
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "scheduled confession is not yet publishable")]
+    fn scheduled_confession_cannot_publish_early() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 65);
+        let commitment = compute_schedule_commitment(&env, &hash, &salt(&env, 9));
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let schedule_id = client.schedule_confession(&author, &commitment, &5_000);
+
+        env.ledger().with_mut(|l| l.timestamp = 4_999);
+        client.publish_scheduled(&schedule_id, &hash, &salt(&env, 9));
+    }
+
+    #[test]
+    #[should_panic(expected = "content does not match schedule commitment")]
+    fn scheduled_confession_rejects_wrong_reveal() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 66);
+        let commitment = compute_schedule_commitment(&env, &hash, &salt(&env, 9));
+        let schedule_id = client.schedule_confession(&author, &commitment, &5_000);
+
+        env.ledger().with_mut(|l| l.timestamp = 5_000);
+        client.publish_scheduled(&schedule_id, &sample_hash(&env, 67), &salt(&env, 9));
+    }
+
+    #[test]
+    fn scheduled_confession_publishes_once() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 68);
+        let commitment = compute_schedule_commitment(&env, &hash, &salt(&env, 9));
+        let schedule_id = client.schedule_confession(&author, &commitment, &5_000);
+        assert!(client
+            .try_schedule_confession(&author, &commitment, &0)
+            .is_err());
+
+        env.ledger().with_mut(|l| l.timestamp = 5_000);
+        client.publish_scheduled(&schedule_id, &hash, &salt(&env, 9));
+        assert!(client
+            .try_publish_scheduled(&schedule_id, &hash, &salt(&env, 9))
+            .is_err());
+    }
+
+    #[test]
+    fn scheduled_confession_is_published_only_by_its_author() {
+        let (env, client, _admin, author) = setup();
+        let hash = sample_hash(&env, 69);
+        let commitment = compute_schedule_commitment(&env, &hash, &salt(&env, 9));
+        let schedule_id = client.schedule_confession(&author, &commitment, &5_000);
+        env.ledger().with_mut(|l| l.timestamp = 5_000);
+
+        // A squatter who got hold of the reveal cannot publish it.
+        env.set_auths(&[]);
+        assert!(client
+            .try_publish_scheduled(&schedule_id, &hash, &salt(&env, 9))
+            .is_err());
+        assert!(client.get_scheduled(&schedule_id).is_some());
+        assert_eq!(client.get_total_count(), 0);
+
+        env.mock_all_auths();
+        let id = client.publish_scheduled(&schedule_id, &hash, &salt(&env, 9));
+        assert_eq!(env.auths()[0].0, author);
+        assert_eq!(client.get_confession(&id).content_hash, hash);
+    }
+
+    #[test]
+    fn committed_confession_rejects_a_reused_commitment() {
+        let (env, client, admin, author) = setup();
+        let relayer = Address::generate(&env);
+        let commitment = compute_author_commitment(&env, &author, &salt(&env, 10));
+        let id = client.create_confession_committed(
+            &relayer,
+            &commitment,
+            &sample_hash(&env, 70),
+            &1_000,
+        );
+
+        assert!(client
+            .try_create_confession_committed(&relayer, &commitment, &sample_hash(&env, 71), &1_000)
+            .is_err());
+
+        // Erasing the unrevealed confession frees the commitment.
+        client.erase_confession(&admin, &id);
+        let id = client.create_confession_committed(
+            &relayer,
+            &commitment,
+            &sample_hash(&env, 71),
+            &1_000,
+        );
+
+        // So does proving authorship, after which the link is public anyway.
+        client.prove_authorship(&author, &id, &salt(&env, 10));
+        client.create_confession_committed(&relayer, &commitment, &sample_hash(&env, 72), &1_000);
+    }
+
+    #[test]
+    fn committed_confession_requires_proof_before_author_can_delete() {
+        let (env, client, _admin, author) = setup();
+        let relayer = Address::generate(&env);
+        let commitment = compute_author_commitment(&env, &author, &salt(&env, 9));
+        let id = client.create_confession_committed(
+            &relayer,
+            &commitment,
+            &sample_hash(&env, 64),
+            &1_000,
+        );
+
+        assert!(client
+            .try_delete_confession(&author, &id, &2_000, &None)
+            .is_err());
+
+        client.prove_authorship(&author, &id, &salt(&env, 9));
+        client.delete_confession(&author, &id, &3_000, &None);
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Deleted);
     }
 
-    // ─── Delete ───
+    mod relayed {
+        extern crate std;
 
-    /// Soft-delete a confession (set status to Deleted).
-    ///
-    /// Only the author or admin can delete.
-    ///
-    /// Emits: `("confession_deleted", id)` → `(caller, timestamp)`
-    pub fn delete_confession(env: Env, caller: Address, id: u64, timestamp: u64) {
-        caller.require_auth();
+        use super::*;
+        use ed25519_dalek::{Signer, SigningKey};
 
-        // Check if paused — use shared emergency pause module
-        emergency_pause::assert_not_paused(&env).unwrap_or_else(|err| panic!("{}", err as u32));
+        fn signing_key(seed: u8) -> SigningKey {
+            SigningKey::from_bytes(&[seed; 32])
+        }
 
-        let mut confession: Confession = env
-            .storage()
-            .instance()
-            .get(&DataKey::Confession(id))
-            .expect("confession not found");
+        fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+            BytesN::from_array(env, &key.verifying_key().to_bytes())
+        }
 
-        // Terminal-state guard — prevents double-delete and misleading updated_at stamps.
-        if confession.status == ConfessionStatus::Deleted {
-            panic!("confession is already deleted");
+        fn sign(
+            env: &Env,
+            client: &ConfessionRegistryClient,
+            key: &SigningKey,
+            method: &str,
+            args: Vec<Val>,
+            nonce: u64,
+        ) -> RelayAuth {
+            let expiry = 100;
+            let message = relay_message(
+                env,
+                &client.address,
+                &Symbol::new(env, method),
+                args,
+                nonce,
+                expiry,
+            );
+            let mut buf = std::vec![0u8; message.len() as usize];
+            message.copy_into_slice(&mut buf);
+            RelayAuth {
+                public_key: public_key(env, key),
+                nonce,
+                expiry,
+                signature: BytesN::from_array(env, &key.sign(&buf).to_bytes()),
+            }
         }
 
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("contract not initialized");
+        fn create_args(env: &Env, hash: &BytesN<32>) -> Vec<Val> {
+            vec![env, hash.clone().into_val(env), 1_000u64.into_val(env)]
+        }
 
-        if caller != confession.author && caller != admin {
-            panic!("unauthorized: only author or admin can delete");
+        fn relayed_create(
+            env: &Env,
+            client: &ConfessionRegistryClient,
+            key: &SigningKey,
+            seed: u8,
+            nonce: u64,
+        ) -> u64 {
+            let hash = sample_hash(env, seed);
+            let auth = sign(
+                env,
+                client,
+                key,
+                "create_confession_relayed",
+                create_args(env, &hash),
+                nonce,
+            );
+            client.create_confession_relayed(&auth, &hash, &1_000)
         }
 
-        confession.status = ConfessionStatus::Deleted;
-        confession.updated_at = timestamp;
+        #[test]
+        fn relayed_create_attributes_confession_to_signer_account() {
+            let (env, client, _admin, _author) = setup();
+            let key = signing_key(1);
 
-        env.storage()
-            .instance()
-            .set(&DataKey::Confession(id), &confession);
+            let id = relayed_create(&env, &client, &key, 1, 1);
 
-        ConfessionDeletedEvent {
-            id,
-            event_version: events::EVENT_VERSION_V1,
-            nonce: bump_confession_event_nonce(&env, id),
-            timestamp,
-            actor: caller,
-            correlation_id: None,
+            let signer = relay_signer_address(&env, &public_key(&env, &key));
+            assert_eq!(client.get_confession(&id).author, Some(signer.clone()));
+            assert_eq!(client.get_expected_nonce(&signer), 2);
         }
-        .publish(&env);
-    }
-
-    /// Replay-protected delete_confession variant.
-    pub fn delete_confession_seq(
-        env: Env,
-        caller: Address,
-        id: u64,
-        timestamp: u64,
-        nonce: u64,
-    ) -> Result<(), ReplayError> {
-        consume_nonce(&env, &caller, nonce)?;
-        Self::delete_confession(env, caller, id, timestamp);
-        Ok(())
-    }
-}
 
-// ─── Tests ───
+        #[test]
+        fn relayed_create_replay_is_rejected() {
+            let (env, client, _admin, _author) = setup();
+            let key = signing_key(2);
+            let hash = sample_hash(&env, 2);
+            let auth = sign(
+                &env,
+                &client,
+                &key,
+                "create_confession_relayed",
+                create_args(&env, &hash),
+                1,
+            );
+
+            client.create_confession_relayed(&auth, &hash, &1_000);
+            let replay = client.try_create_confession_relayed(&auth, &hash, &1_000);
+            assert_eq!(replay, Err(Ok(ReplayError::InvalidNonce)));
+        }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+        #[test]
+        fn relayed_create_after_expiry_is_rejected() {
+            let (env, client, _admin, _author) = setup();
+            let key = signing_key(3);
+            let hash = sample_hash(&env, 3);
+            let auth = sign(
+                &env,
+                &client,
+                &key,
+                "create_confession_relayed",
+                create_args(&env, &hash),
+                1,
+            );
+
+            env.ledger().with_mut(|l| l.timestamp = auth.expiry + 1);
+            let res = client.try_create_confession_relayed(&auth, &hash, &1_000);
+            assert_eq!(res, Err(Ok(ReplayError::RequestExpired)));
+        }
 
-    fn setup() -> (Env, ConfessionRegistryClient<'static>, Address, Address) {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract_id = env.register(ConfessionRegistry, ());
-        let client = ConfessionRegistryClient::new(&env, &contract_id);
+        #[test]
+        fn relayed_create_with_tampered_args_is_rejected() {
+            let (env, client, _admin, _author) = setup();
+            let key = signing_key(4);
+            let hash = sample_hash(&env, 4);
+            let auth = sign(
+                &env,
+                &client,
+                &key,
+                "create_confession_relayed",
+                create_args(&env, &hash),
+                1,
+            );
+
+            let res = client.try_create_confession_relayed(&auth, &sample_hash(&env, 5), &1_000);
+            assert!(res.is_err());
+            assert_eq!(client.get_total_count(), 0);
+        }
 
-        let admin = Address::generate(&env);
-        let author = Address::generate(&env);
+        #[test]
+        fn relayed_update_and_delete_act_as_signer() {
+            let (env, client, _admin, _author) = setup();
+            let key = signing_key(6);
+            let id = relayed_create(&env, &client, &key, 6, 1);
+
+            let args = vec![
+                &env,
+                id.into_val(&env),
+                ConfessionStatus::Flagged.into_val(&env),
+                2_000u64.into_val(&env),
+            ];
+            let auth = sign(&env, &client, &key, "update_status_relayed", args, 2);
+            client.update_status_relayed(&auth, &id, &ConfessionStatus::Flagged, &2_000);
+            assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
+
+            let args = vec![&env, id.into_val(&env), 3_000u64.into_val(&env)];
+            let auth = sign(&env, &client, &key, "delete_confession_relayed", args, 3);
+            client.delete_confession_relayed(&auth, &id, &3_000);
+            assert_eq!(client.get_confession(&id).status, ConfessionStatus::Deleted);
+        }
 
-        client.initialize(&admin);
+        #[test]
+        fn relayed_ephemeral_create_sets_expiry() {
+            let (env, client, _admin, _author) = setup();
+            let key = signing_key(9);
+            let hash = sample_hash(&env, 9);
+            let args = vec![
+                &env,
+                hash.clone().into_val(&env),
+                1_000u64.into_val(&env),
+                50u64.into_val(&env),
+            ];
+            let auth = sign(&env, &client, &key, "create_ephemeral_relayed", args, 1);
+
+            let id = client.create_ephemeral_relayed(&auth, &hash, &1_000, &50);
+            assert_eq!(client.get_confession(&id).expires_at, Some(50));
+            assert!(client
+                .try_create_ephemeral_relayed(&auth, &sample_hash(&env, 10), &1_000, &60)
+                .is_err());
+        }
 
-        (env, client, admin, author)
-    }
+        #[test]
+        #[should_panic(expected = "unauthorized")]
+        fn relayed_delete_by_other_signer_is_rejected() {
+            let (env, client, _admin, _author) = setup();
+            let id = relayed_create(&env, &client, &signing_key(7), 7, 1);
 
-    fn sample_hash(env: &Env, value: u8) -> BytesN<32> {
-        let mut bytes: [u8; 32] = [0; 32];
-        bytes[0] = value;
-        BytesN::from_array(env, &bytes)
+            let other = signing_key(8);
+            let args = vec![&env, id.into_val(&env), 3_000u64.into_val(&env)];
+            let auth = sign(&env, &client, &other, "delete_confession_relayed", args, 1);
+            client.delete_confession_relayed(&auth, &id, &3_000);
+        }
     }
 
     #[test]
-    fn test_create_and_read_confession() {
+    fn ephemeral_confession_reads_as_expired_after_deadline() {
         let (env, client, _admin, author) = setup();
-        let hash = sample_hash(&env, 1);
-        let ts: u64 = 1_700_000_000_000;
-
-        let id = client.create_confession(&author, &hash, &ts);
-        assert_eq!(id, 1);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
 
+        let id = client.create_ephemeral_confession(&author, &sample_hash(&env, 70), &1, &1_100);
         let conf = client.get_confession(&id);
-        assert_eq!(conf.id, 1);
-        assert_eq!(conf.author, author);
-        assert_eq!(conf.content_hash, hash);
-        assert_eq!(conf.created_at, ts);
-        assert_eq!(conf.updated_at, 0);
         assert_eq!(conf.status, ConfessionStatus::Active);
+        assert_eq!(conf.expires_at, Some(1_100));
+
+        env.ledger().with_mut(|l| l.timestamp = 1_100);
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Expired);
+        let page = client.list_confessions(&None, &10);
+        assert_eq!(page.items.get(0).unwrap().status, ConfessionStatus::Expired);
+
+        assert!(client
+            .try_update_status(&author, &id, &ConfessionStatus::Flagged, &2, &None)
+            .is_err());
     }
 
     #[test]
-    fn test_get_by_hash() {
+    #[should_panic(expected = "expiry must be in the future")]
+    fn ephemeral_confession_rejects_past_expiry() {
         let (env, client, _admin, author) = setup();
-        let hash = sample_hash(&env, 2);
-        let ts: u64 = 1_700_000_000_001;
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
 
-        let id = client.create_confession(&author, &hash, &ts);
-        let found_id = client.get_by_hash(&hash);
-        assert_eq!(id, found_id);
+        client.create_ephemeral_confession(&author, &sample_hash(&env, 71), &1, &1_000);
     }
 
     #[test]
-    #[should_panic(expected = "confession with this content hash already exists")]
-    fn test_duplicate_content_hash_rejected() {
+    fn purge_expired_removes_only_expired_records() {
         let (env, client, _admin, author) = setup();
-        let hash = sample_hash(&env, 3);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+        let keep = client.create_confession(&author, &sample_hash(&env, 72), &1, &None);
+        let gone = client.create_ephemeral_confession(&author, &sample_hash(&env, 73), &1, &1_050);
+        let later = client.create_ephemeral_confession(&author, &sample_hash(&env, 74), &1, &5_000);
 
-        client.create_confession(&author, &hash, &1_700_000_000_000);
-        client.create_confession(&author, &hash, &1_700_000_000_001); // panic
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let result = client.purge_expired(&None, &10);
+        assert_eq!(result.purged, 1);
+        assert_eq!(result.next_cursor, None);
+
+        assert!(client.try_get_confession(&gone).is_err());
+        assert!(client.try_get_by_hash(&sample_hash(&env, 73)).is_err());
+        assert_eq!(
+            client.get_confession(&keep).status,
+            ConfessionStatus::Active
+        );
+        assert_eq!(
+            client.get_confession(&later).status,
+            ConfessionStatus::Active
+        );
+
+        // The purged content hash may be reused.
+        client.create_confession(&author, &sample_hash(&env, 73), &2, &None);
+
+        let page = client.list_confessions(&None, &10);
+        assert_eq!(page.items.len(), 3);
     }
 
     #[test]
-    fn test_author_confessions_index() {
+    fn purge_expired_is_bounded_and_resumable() {
         let (env, client, _admin, author) = setup();
-        let hash1 = sample_hash(&env, 10);
-        let hash2 = sample_hash(&env, 11);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        for seed in 0..5u8 {
+            client.create_ephemeral_confession(&author, &sample_hash(&env, 80 + seed), &1, &1_001);
+        }
+        env.ledger().with_mut(|l| l.timestamp = 1_001);
 
-        client.create_confession(&author, &hash1, &1_700_000_000_001);
-        client.create_confession(&author, &hash2, &1_700_000_000_002);
+        let first = client.purge_expired(&None, &2);
+        assert_eq!(first.purged, 2);
+        assert_eq!(first.next_cursor, Some(2));
 
-        let ids = client.get_author_confessions(&author);
-        assert_eq!(ids.len(), 2);
-        assert_eq!(ids.get(0).unwrap(), 1);
-        assert_eq!(ids.get(1).unwrap(), 2);
+        let second = client.purge_expired(&first.next_cursor, &2);
+        assert_eq!(second.purged, 2);
+        assert_eq!(second.next_cursor, Some(4));
+
+        let last = client.purge_expired(&second.next_cursor, &2);
+        assert_eq!(last.purged, 1);
+        assert_eq!(last.next_cursor, None);
+        assert_eq!(client.list_confessions(&None, &10).items.len(), 0);
     }
 
     #[test]
-    fn test_total_count() {
+    fn ephemeral_confession_is_stored_outside_instance_storage() {
         let (env, client, _admin, author) = setup();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let id = client.create_ephemeral_confession(&author, &sample_hash(&env, 75), &1, &1_100);
 
-        assert_eq!(client.get_total_count(), 0);
-
-        client.create_confession(&author, &sample_hash(&env, 20), &1_000);
-        assert_eq!(client.get_total_count(), 1);
+        env.as_contract(&client.address, || {
+            let key = DataKey::Confession(id);
+            assert!(!env.storage().instance().has(&key));
+            assert!(env.storage().persistent().has(&key));
+        });
+        client.update_status(&author, &id, &ConfessionStatus::Flagged, &2, &None);
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
+    }
 
-        client.create_confession(&author, &sample_hash(&env, 21), &2_000);
-        assert_eq!(client.get_total_count(), 2);
+    #[test]
+    fn create_ephemeral_seq_sets_expiry_and_consumes_nonce() {
+        let (env, client, _admin, author) = setup();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+        let id =
+            client.create_ephemeral_seq(&author, &sample_hash(&env, 76), &1, &1_100, &1, &None);
+        assert_eq!(client.get_confession(&id).expires_at, Some(1_100));
+        let replay =
+            client.try_create_ephemeral_seq(&author, &sample_hash(&env, 77), &1, &1_100, &1, &None);
+        assert_eq!(replay, Err(Ok(ReplayError::InvalidNonce)));
     }
 
     #[test]
-    fn test_update_status_by_author() {
+    fn purge_expired_clears_author_index_and_counters() {
         let (env, client, _admin, author) = setup();
-        let hash = sample_hash(&env, 30);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let keep = client.create_confession(&author, &sample_hash(&env, 78), &1, &None);
+        client.create_ephemeral_confession(&author, &sample_hash(&env, 79), &1, &1_050);
+
+        let counters = client.get_checkpoint().counters;
+        assert_eq!((counters.active, counters.ephemeral), (1, 1));
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        client.purge_expired(&None, &10);
+
+        assert_eq!(client.get_author_confession_count(&author), 1);
+        let listed = client.list_author_confessions(&author, &None, &10).items;
+        assert_eq!(listed, vec![&env, keep]);
+        let counters = client.get_checkpoint().counters;
+        assert_eq!((counters.active, counters.ephemeral), (1, 0));
+        assert_eq!(counters.purged, 1);
+    }
 
-        let id = client.create_confession(&author, &hash, &1_000);
-        client.update_status(&author, &id, &ConfessionStatus::Flagged, &2_000);
+    #[test]
+    #[should_panic(expected = "limit must be positive")]
+    fn purge_expired_rejects_zero_limit() {
+        let (_env, client, _admin, _author) = setup();
+        client.purge_expired(&None, &0);
+    }
 
-        let conf = client.get_confession(&id);
-        assert_eq!(conf.status, ConfessionStatus::Flagged);
-        assert_eq!(conf.updated_at, 2_000);
+    #[test]
+    fn purge_expired_past_the_last_cursor_ends_the_sweep() {
+        let (env, client, _admin, author) = setup();
+        client.create_confession(&author, &sample_hash(&env, 86), &1, &None);
+
+        let result = client.purge_expired(&Some(u64::MAX), &10);
+        assert_eq!(result.purged, 0);
+        assert_eq!(result.next_cursor, None);
     }
 
     #[test]
-    fn test_update_status_by_admin() {
-        let (env, client, admin, author) = setup();
-        let hash = sample_hash(&env, 31);
+    fn erase_confession_leaves_only_tombstone() {
+        let (env, client, _admin, author) = setup();
+        env.ledger().with_mut(|l| l.timestamp = 4_000);
+        let hash = sample_hash(&env, 90);
+        let id = client.create_confession(&author, &hash, &1_000, &None);
 
-        let id = client.create_confession(&author, &hash, &1_000);
-        client.update_status(&admin, &id, &ConfessionStatus::Flagged, &2_000);
+        client.erase_confession(&author, &id);
 
-        let conf = client.get_confession(&id);
-        assert_eq!(conf.status, ConfessionStatus::Flagged);
+        assert_eq!(
+            client.get_tombstone(&id),
+            Some(ConfessionTombstone {
+                id,
+                erased_at: 4_000
+            })
+        );
+        assert!(client.try_get_confession(&id).is_err());
+        assert!(client.try_get_by_hash(&hash).is_err());
+        assert_eq!(client.get_author_confession_count(&author), 0);
+        assert_eq!(
+            client
+                .list_author_confessions(&author, &None, &10)
+                .items
+                .len(),
+            0
+        );
+        assert_eq!(client.list_confessions(&None, &10).items.len(), 0);
     }
 
     #[test]
-    #[should_panic(expected = "unauthorized")]
-    fn test_update_status_by_unauthorized_user() {
+    #[should_panic(expected = "confession has been erased")]
+    fn get_erased_confession_reports_erasure() {
         let (env, client, _admin, author) = setup();
-        let outsider = Address::generate(&env);
-        let hash = sample_hash(&env, 32);
+        let id = client.create_confession(&author, &sample_hash(&env, 91), &1_000, &None);
+        client.erase_confession(&author, &id);
 
-        let id = client.create_confession(&author, &hash, &1_000);
-        client.update_status(&outsider, &id, &ConfessionStatus::Flagged, &2_000);
-        // panic
+        client.get_confession(&id);
     }
 
     #[test]
-    fn test_delete_confession() {
-        let (env, client, _admin, author) = setup();
-        let hash = sample_hash(&env, 40);
+    fn erase_keeps_other_author_index_positions() {
+        let (env, client, admin, author) = setup();
+        let first = client.create_confession(&author, &sample_hash(&env, 92), &1_000, &None);
+        let second = client.create_confession(&author, &sample_hash(&env, 93), &1_001, &None);
+        let third = client.create_confession(&author, &sample_hash(&env, 94), &1_002, &None);
 
-        let id = client.create_confession(&author, &hash, &1_000);
-        client.delete_confession(&author, &id, &3_000);
+        client.erase_confession(&admin, &second);
 
-        let conf = client.get_confession(&id);
-        assert_eq!(conf.status, ConfessionStatus::Deleted);
-        assert_eq!(conf.updated_at, 3_000);
+        let ids = client.list_author_confessions(&author, &None, &10).items;
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids.get(0).unwrap(), first);
+        assert_eq!(ids.get(1).unwrap(), third);
+        assert_eq!(client.get_author_confession_count(&author), 2);
+        assert_eq!(client.get_tombstone(&first), None);
     }
 
     #[test]
     #[should_panic(expected = "unauthorized")]
-    fn test_delete_by_unauthorized_user() {
+    fn erase_by_outsider_is_rejected() {
         let (env, client, _admin, author) = setup();
         let outsider = Address::generate(&env);
-        let hash = sample_hash(&env, 41);
+        let id = client.create_confession(&author, &sample_hash(&env, 95), &1_000, &None);
 
-        let id = client.create_confession(&author, &hash, &1_000);
-        client.delete_confession(&outsider, &id, &2_000); // panic
+        client.erase_confession(&outsider, &id);
     }
 
-    #[test]
-    #[should_panic(expected = "confession not found")]
-    fn test_get_nonexistent_confession() {
-        let (_env, client, _admin, _author) = setup();
-        client.get_confession(&999);
+    /// `correlation_id` of the most recent event, which is always the last
+    /// data field of the registry's confession events.
+    fn last_correlation_id(env: &Env) -> Option<Symbol> {
+        let (_, _, data) = env.events().all().last().unwrap();
+        let fields = Vec::<Val>::try_from_val(env, &data).unwrap();
+        Option::<Symbol>::try_from_val(env, &fields.last().unwrap()).unwrap()
     }
 
     #[test]
-    fn test_governance_flow() {
-        let (env, client, admin, _author) = setup();
-
-        let new_admin = Address::generate(&env);
-        let action = governance::model::CriticalAction::GrantAdmin(new_admin.clone());
-
-        // Propose
-        let id = client.gov_propose(&admin, &action);
+    fn correlation_ids_are_published_in_events() {
+        let (env, client, _admin, author) = setup();
+        let trace = Some(Symbol::new(&env, "req_7f3a"));
 
-        // Approve (default quorum is 1)
-        client.gov_approve(&admin, &id);
+        let id = client.create_confession(&author, &sample_hash(&env, 100), &1_000, &trace);
+        assert_eq!(last_correlation_id(&env), trace);
 
-        // Execute
-        client.gov_execute(&admin, &id);
+        client.update_status(&author, &id, &ConfessionStatus::Flagged, &1_001, &None);
+        assert_eq!(last_correlation_id(&env), None);
 
-        // Verify
-        let is_adm = env.as_contract(&client.address, || {
-            access_control::is_admin(&env, &new_admin)
-        });
-        assert!(is_adm);
+        let delete_trace = Some(Symbol::new(&env, "req_7f3b"));
+        client.delete_confession(&author, &id, &1_002, &delete_trace);
+        assert_eq!(last_correlation_id(&env), delete_trace);
     }
 
     #[test]
-    fn test_governance_quorum() {
-        let (env, client, admin, _author) = setup();
-        let admin2 = Address::generate(&env);
-
-        // Grant second admin first
-        let grant_id = client.gov_propose(
-            &admin,
-            &governance::model::CriticalAction::GrantAdmin(admin2.clone()),
+    fn seq_variants_publish_correlation_ids() {
+        let (env, client, _admin, author) = setup();
+        let trace = Some(Symbol::new(&env, "seq_trace"));
+
+        let id = client.create_confession_seq(
+            &author,
+            &sample_hash(&env, 101),
+            &1_000,
+            &1,
+            &None,
+            &trace,
         );
-        client.gov_approve(&admin, &grant_id);
-        client.gov_execute(&admin, &grant_id);
-
-        // Set quorum to 2
-        client.set_quorum(&2);
-
-        let new_admin = Address::generate(&env);
-        let action = governance::model::CriticalAction::GrantAdmin(new_admin.clone());
-
-        let id = client.gov_propose(&admin, &action);
-
-        // Approve 1/2
-        client.gov_approve(&admin, &id);
-
-        // Execute (should fail)
-        let res = client.try_gov_execute(&admin, &id);
-        assert!(res.is_err());
+        assert_eq!(last_correlation_id(&env), trace);
+
+        client.update_status_seq(
+            &author,
+            &id,
+            &ConfessionStatus::Flagged,
+            &1_001,
+            &2,
+            &None,
+            &trace,
+        );
+        assert_eq!(last_correlation_id(&env), trace);
     }
 
     #[test]
-    fn test_execute_without_quorum() {
-        let (_env, client, admin, _author) = setup();
-        client.set_quorum(&2);
+    #[should_panic(expected = "invalid correlation id")]
+    fn empty_correlation_id_is_rejected() {
+        let (env, client, _admin, author) = setup();
 
-        let id = client.gov_propose(&admin, &governance::model::CriticalAction::Pause);
-        client.gov_approve(&admin, &id);
-        let result = client.try_gov_execute(&admin, &id);
-        assert!(result.is_err());
+        client.create_confession(
+            &author,
+            &sample_hash(&env, 102),
+            &1_000,
+            &Some(Symbol::new(&env, "")),
+        );
     }
 
     #[test]
-    fn test_governance_revoke() {
-        let (_env, client, admin, _author) = setup();
-        let id = client.gov_propose(&admin, &governance::model::CriticalAction::Pause);
+    fn operator_can_moderate_any_confession() {
+        let (env, client, admin, author) = setup();
+        let moderator = Address::generate(&env);
+        client.grant_operator(&admin, &moderator);
+        let id = client.create_confession(&author, &sample_hash(&env, 110), &1_000, &None);
 
-        client.gov_approve(&admin, &id);
-        client.gov_revoke(&admin, &id);
+        client.update_status(&moderator, &id, &ConfessionStatus::Flagged, &1_001, &None);
+        client.delete_confession(&moderator, &id, &1_002, &None);
 
-        // Try to execute (should fail since 0/1 approvals now)
-        let res = client.try_gov_execute(&admin, &id);
-        assert!(res.is_err());
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Deleted);
     }
 
     #[test]
-    fn test_pause_via_governance() {
+    #[should_panic(expected = "unauthorized")]
+    fn revoked_operator_cannot_moderate() {
         let (env, client, admin, author) = setup();
-        let hash = sample_hash(&env, 50);
-
-        // Propose Pause
-        let id = client.gov_propose(&admin, &governance::model::CriticalAction::Pause);
-        client.gov_approve(&admin, &id);
-        client.gov_execute(&admin, &id);
+        let moderator = Address::generate(&env);
+        client.grant_operator(&admin, &moderator);
+        client.revoke_operator(&admin, &moderator);
+        let id = client.create_confession(&author, &sample_hash(&env, 111), &1_000, &None);
 
-        // Try to create confession (should fail)
-        let res = client.try_create_confession(&author, &hash, &1_000);
-        assert!(res.is_err());
+        client.update_status(&moderator, &id, &ConfessionStatus::Flagged, &1_001, &None);
+    }
 
-        // Unpause
-        let id2 = client.gov_propose(&admin, &governance::model::CriticalAction::Unpause);
-        client.gov_approve(&admin, &id2);
-        client.gov_execute(&admin, &id2);
+    #[test]
+    #[should_panic(expected = "unauthorized")]
+    fn operator_cannot_erase() {
+        let (env, client, admin, author) = setup();
+        let moderator = Address::generate(&env);
+        client.grant_operator(&admin, &moderator);
+        let id = client.create_confession(&author, &sample_hash(&env, 112), &1_000, &None);
 
-        // Try to create confession (should succeed)
-        client.create_confession(&author, &hash, &2_000);
+        client.erase_confession(&moderator, &id);
     }
 
     #[test]
-    #[should_panic(expected = "already initialized")]
-    fn test_double_initialization() {
-        let (env, client, _admin, _author) = setup();
-        let another = Address::generate(&env);
-        client.initialize(&another); // should panic
+    fn governance_granted_admin_gains_registry_powers() {
+        let (env, client, admin, author) = setup();
+        let new_admin = Address::generate(&env);
+        let proposal = client.gov_propose(
+            &admin,
+            &governance::model::CriticalAction::GrantAdmin(new_admin.clone()),
+        );
+        client.gov_approve(&admin, &proposal);
+        client.gov_execute(&admin, &proposal);
+
+        let id = client.create_confession(&author, &sample_hash(&env, 113), &1_000, &None);
+        client.update_status(&new_admin, &id, &ConfessionStatus::Flagged, &1_001, &None);
+        client.configure_author_rate_limit(&new_admin, &5, &60);
+
+        assert_eq!(client.get_confession(&id).status, ConfessionStatus::Flagged);
     }
 
     #[test]
-    fn author_confession_index_exact_limit_succeeds() {
-        let (env, client, _admin, author) = setup();
+    fn migrate_legacy_admin_without_owner() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ConfessionRegistry, ());
+        let client = ConfessionRegistryClient::new(&env, &contract_id);
+        let legacy = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage().instance().set(&DataKey::Admin, &legacy);
+            env.storage().instance().set(&DataKey::NextId, &1u64);
+        });
 
-        for seed in 0..MAX_AUTHOR_CONFESSIONS_PER_AUTHOR {
-            let hash = sample_hash(&env, seed as u8);
-            let id = client.create_confession(&author, &hash, &(1_000 + seed as u64));
-            assert_eq!(id, seed as u64 + 1);
-        }
+        client.migrate_legacy_admin();
 
-        assert_eq!(
-            client.get_total_count(),
-            MAX_AUTHOR_CONFESSIONS_PER_AUTHOR as u64
-        );
-        assert_eq!(
-            client.get_author_confessions(&author).len(),
-            MAX_AUTHOR_CONFESSIONS_PER_AUTHOR
-        );
+        assert_eq!(client.get_owner(), legacy);
+        env.as_contract(&contract_id, || {
+            assert!(!env.storage().instance().has(&DataKey::Admin));
+        });
+        assert!(client.try_migrate_legacy_admin().is_err());
+        assert!(client.try_initialize(&legacy).is_err());
     }
 
     #[test]
-    #[should_panic(expected = "registry payload too long")]
-    fn author_confession_index_limit_plus_one_rejected() {
-        let (env, client, _admin, author) = setup();
+    fn migrate_legacy_admin_that_differs_from_owner() {
+        let (env, client, admin, _author) = setup();
+        let legacy = Address::generate(&env);
+        env.as_contract(&client.address, || {
+            env.storage().instance().set(&DataKey::Admin, &legacy);
+        });
 
-        for seed in 0..MAX_AUTHOR_CONFESSIONS_PER_AUTHOR {
-            let hash = sample_hash(&env, seed as u8);
-            client.create_confession(&author, &hash, &(1_000 + seed as u64));
-        }
+        client.migrate_legacy_admin();
 
-        let hash = sample_hash(&env, MAX_AUTHOR_CONFESSIONS_PER_AUTHOR as u8);
-        let _ = client.create_confession(&author, &hash, &9_999);
+        assert_eq!(client.get_owner(), admin);
+        assert!(client.is_admin(&legacy));
     }
 }
@@ -19,7 +19,7 @@ fn benchmark_create_confession() {
     let ts: u64 = 1_000;
 
     env.cost_estimate().budget().reset_default();
-    client.create_confession(&author, &hash, &ts);
+    client.create_confession(&author, &hash, &ts, &None);
 
     let cpu = env.cost_estimate().budget().cpu_instruction_cost();
     let mem = env.cost_estimate().budget().memory_bytes_cost();
@@ -34,10 +34,10 @@ fn benchmark_update_status() {
     let (client, _admin, author) = setup(&env);
     let hash = BytesN::from_array(&env, &[1; 32]);
     let ts: u64 = 1_000;
-    let id = client.create_confession(&author, &hash, &ts);
+    let id = client.create_confession(&author, &hash, &ts, &None);
 
     env.cost_estimate().budget().reset_default();
-    client.update_status(&author, &id, &ConfessionStatus::Flagged, &2_000);
+    client.update_status(&author, &id, &ConfessionStatus::Flagged, &2_000, &None);
 
     let cpu = env.cost_estimate().budget().cpu_instruction_cost();
     let mem = env.cost_estimate().budget().memory_bytes_cost();
@@ -1,7 +1,17 @@
-use anonymous_tipping::{AnonymousTipping, AnonymousTippingClient};
-use confession_registry::{ConfessionRegistry, ConfessionRegistryClient, ConfessionStatus};
+use anonymous_tipping::{
+    testutils::{refund_commitment, refund_salt, tipper},
+    AnonymousTipping, AnonymousTippingClient, Error as TipError,
+};
+use confession_registry::{
+    compute_author_commitment, Confession, ConfessionRegistry, ConfessionRegistryClient,
+    ConfessionStatus,
+};
 use reputation_badges::{BadgeType, ReputationBadges, ReputationBadgesClient};
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::TokenClient,
+    vec, Address, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val,
+};
 
 const TS_CREATE: u64 = 1_710_000_000;
 const TS_FLAG: u64 = 1_710_000_050;
@@ -38,7 +48,12 @@ fn setup() -> (
 
     registry.initialize(&admin);
     badges.initialize(&admin);
-    tipping.init();
+    tipping.init(
+        &env.register_stellar_asset_contract_v2(admin.clone())
+            .address(),
+    );
+    tipping.configure_controls(&admin, &1_000, &60);
+    tipping.set_confession_registry(&admin, &registry_id);
 
     (env, registry, badges, tipping, admin, author)
 }
@@ -46,8 +61,11 @@ fn setup() -> (
 #[test]
 fn confession_reputation_tipping_happy_path_is_repeatable() {
     let (env, registry, badges, tipping, admin, author) = setup();
+    let token = tipping.get_token().unwrap();
+    let tipper = tipper(&env, &tipping);
 
-    let confession_id = registry.create_confession(&author, &fixture_hash(&env, 0x21), &TS_CREATE);
+    let confession_id =
+        registry.create_confession(&author, &fixture_hash(&env, 0x21), &TS_CREATE, &None);
     assert_eq!(confession_id, 1);
 
     let badge_id = badges.award_badge(&author, &BadgeType::ConfessionStarter);
@@ -61,9 +79,9 @@ fn confession_reputation_tipping_happy_path_is_repeatable() {
     );
     assert_eq!(new_rep, 100);
 
-    let settlement_id = tipping.send_tip(&author, &TIP_AMOUNT);
+    let settlement_id = tipping.send_tip(&tipper, &author, &token, &TIP_AMOUNT);
     assert_eq!(settlement_id, 1);
-    assert_eq!(tipping.get_tips(&author), TIP_AMOUNT);
+    assert_eq!(tipping.get_tips(&author, &token), TIP_AMOUNT);
 
     let confession = registry.get_confession(&confession_id);
     assert_eq!(confession.status, ConfessionStatus::Active);
@@ -77,15 +95,26 @@ fn confession_reputation_tipping_happy_path_is_repeatable() {
 #[test]
 fn flagged_confession_flow_keeps_cross_contract_state_consistent() {
     let (env, registry, badges, tipping, admin, author) = setup();
+    let token = tipping.get_token().unwrap();
+    let tipper = tipper(&env, &tipping);
 
-    let confession_id = registry.create_confession(&author, &fixture_hash(&env, 0x41), &TS_CREATE);
-    registry.update_status(&admin, &confession_id, &ConfessionStatus::Flagged, &TS_FLAG);
+    let confession_id =
+        registry.create_confession(&author, &fixture_hash(&env, 0x41), &TS_CREATE, &None);
+    registry.update_status(
+        &admin,
+        &confession_id,
+        &ConfessionStatus::Flagged,
+        &TS_FLAG,
+        &None,
+    );
 
     let badge_id = badges.award_badge(&author, &BadgeType::PopularVoice);
     assert_eq!(badge_id, 1);
 
     let settlement_id = tipping.send_tip_with_proof(
+        &tipper,
         &author,
+        &token,
         &TIP_AMOUNT,
         &Some(String::from_str(&env, "cross-contract moderation fixture")),
     );
@@ -96,8 +125,329 @@ fn flagged_confession_flow_keeps_cross_contract_state_consistent() {
         ConfessionStatus::Flagged
     );
     assert!(badges.has_badge(&author, &BadgeType::PopularVoice));
-    assert_eq!(tipping.get_tips(&author), TIP_AMOUNT);
+    assert_eq!(tipping.get_tips(&author, &token), TIP_AMOUNT);
     assert_eq!(registry.get_total_count(), 1);
     assert_eq!(badges.get_total_badges(), 1);
     assert_eq!(tipping.latest_settlement_nonce(), 1);
 }
+
+#[test]
+fn confession_tip_is_escrowed_for_the_author_only() {
+    let (env, registry, _badges, tipping, admin, author) = setup();
+    let token = TokenClient::new(&env, &tipping.get_token().unwrap());
+    let tipper = tipper(&env, &tipping);
+    let payout = Address::generate(&env);
+
+    let confession_id =
+        registry.create_confession(&author, &fixture_hash(&env, 0x51), &TS_CREATE, &None);
+    let settlement_id = tipping.tip_confession(
+        &tipper,
+        &registry.address,
+        &confession_id,
+        &token.address,
+        &TIP_AMOUNT,
+        &refund_commitment(&env, &tipper),
+    );
+    assert_eq!(settlement_id, 1);
+    assert_eq!(
+        tipping.get_confession_escrow(&registry.address, &confession_id, &token.address),
+        TIP_AMOUNT
+    );
+    // Nothing is credited to the author's address until they claim.
+    assert_eq!(tipping.get_tips(&author, &token.address), 0);
+
+    assert_eq!(
+        tipping.claim_confession_tips(&registry.address, &confession_id, &token.address, &payout),
+        TIP_AMOUNT
+    );
+    assert_eq!(token.balance(&payout), TIP_AMOUNT);
+    assert_eq!(
+        tipping.get_confession_escrow(&registry.address, &confession_id, &token.address),
+        0
+    );
+    assert_eq!(
+        tipping.try_claim_confession_tips(
+            &registry.address,
+            &confession_id,
+            &token.address,
+            &payout
+        ),
+        Err(Ok(TipError::NothingToClaim))
+    );
+
+    let _ = admin;
+}
+
+#[test]
+fn confession_tip_claim_requires_author_auth() {
+    let (env, registry, _badges, tipping, _admin, author) = setup();
+    let token = tipping.get_token().unwrap();
+    let tipper = tipper(&env, &tipping);
+    let thief = Address::generate(&env);
+
+    let confession_id =
+        registry.create_confession(&author, &fixture_hash(&env, 0x52), &TS_CREATE, &None);
+    tipping.tip_confession(
+        &tipper,
+        &registry.address,
+        &confession_id,
+        &token,
+        &TIP_AMOUNT,
+        &refund_commitment(&env, &tipper),
+    );
+
+    env.set_auths(&[]);
+    assert!(tipping
+        .try_claim_confession_tips(&registry.address, &confession_id, &token, &thief)
+        .is_err());
+    assert_eq!(
+        tipping.get_confession_escrow(&registry.address, &confession_id, &token),
+        TIP_AMOUNT
+    );
+}
+
+#[test]
+fn pseudonymous_confession_tips_wait_for_proven_authorship() {
+    let (env, registry, _badges, tipping, _admin, author) = setup();
+    let token = tipping.get_token().unwrap();
+    let tipper = tipper(&env, &tipping);
+    let submitter = Address::generate(&env);
+    let salt = fixture_hash(&env, 0x99);
+
+    let confession_id = registry.create_confession_committed(
+        &submitter,
+        &compute_author_commitment(&env, &author, &salt),
+        &fixture_hash(&env, 0x53),
+        &TS_CREATE,
+    );
+    tipping.tip_confession(
+        &tipper,
+        &registry.address,
+        &confession_id,
+        &token,
+        &TIP_AMOUNT,
+        &refund_commitment(&env, &tipper),
+    );
+    assert_eq!(
+        tipping.try_claim_confession_tips(&registry.address, &confession_id, &token, &author),
+        Err(Ok(TipError::AuthorNotRevealed))
+    );
+
+    registry.prove_authorship(&author, &confession_id, &salt);
+    assert_eq!(
+        tipping.claim_confession_tips(&registry.address, &confession_id, &token, &author),
+        TIP_AMOUNT
+    );
+}
+
+#[test]
+fn missing_or_deleted_confessions_cannot_be_tipped() {
+    let (env, registry, _badges, tipping, _admin, author) = setup();
+    let token = tipping.get_token().unwrap();
+    let tipper = tipper(&env, &tipping);
+
+    assert_eq!(
+        tipping.try_tip_confession(
+            &tipper,
+            &registry.address,
+            &42,
+            &token,
+            &TIP_AMOUNT,
+            &refund_commitment(&env, &tipper)
+        ),
+        Err(Ok(TipError::ConfessionNotFound))
+    );
+
+    let confession_id =
+        registry.create_confession(&author, &fixture_hash(&env, 0x54), &TS_CREATE, &None);
+    registry.delete_confession(&author, &confession_id, &TS_FLAG, &None);
+    assert_eq!(
+        tipping.try_tip_confession(
+            &tipper,
+            &registry.address,
+            &confession_id,
+            &token,
+            &TIP_AMOUNT,
+            &refund_commitment(&env, &tipper)
+        ),
+        Err(Ok(TipError::ConfessionNotTippable))
+    );
+    assert_eq!(tipping.latest_settlement_nonce(), 0);
+}
+
+#[test]
+fn only_tips_after_the_last_claim_are_refundable() {
+    let (env, registry, _badges, tipping, _admin, author) = setup();
+    let token = tipping.get_token().unwrap();
+    let tipper = tipper(&env, &tipping);
+
+    let confession_id =
+        registry.create_confession(&author, &fixture_hash(&env, 0x55), &TS_CREATE, &None);
+    let claimed = tipping.tip_confession(
+        &tipper,
+        &registry.address,
+        &confession_id,
+        &token,
+        &10,
+        &refund_commitment(&env, &tipper),
+    );
+    tipping.claim_confession_tips(&registry.address, &confession_id, &token, &author);
+    let unclaimed = tipping.tip_confession(
+        &tipper,
+        &registry.address,
+        &confession_id,
+        &token,
+        &7,
+        &refund_commitment(&env, &tipper),
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += AnonymousTipping::ESCROW_CLAIM_WINDOW_SECONDS);
+    assert_eq!(
+        tipping.try_refund_tip(&claimed, &tipper, &refund_salt(&env)),
+        Err(Ok(TipError::TipAlreadyClaimed))
+    );
+    assert_eq!(
+        tipping.refund_tip(&unclaimed, &tipper, &refund_salt(&env)),
+        7
+    );
+    assert_eq!(
+        tipping.get_confession_escrow(&registry.address, &confession_id, &token),
+        0
+    );
+}
+
+#[test]
+fn tips_only_go_through_the_configured_registry() {
+    let (env, registry, _badges, tipping, admin, author) = setup();
+    let token = tipping.get_token().unwrap();
+    let tipper = tipper(&env, &tipping);
+    let rogue = ConfessionRegistryClient::new(&env, &env.register(ConfessionRegistry, ()));
+    rogue.initialize(&admin);
+    let confession_id =
+        rogue.create_confession(&author, &fixture_hash(&env, 0x56), &TS_CREATE, &None);
+
+    assert_eq!(
+        tipping.try_tip_confession(
+            &tipper,
+            &rogue.address,
+            &confession_id,
+            &token,
+            &TIP_AMOUNT,
+            &refund_commitment(&env, &tipper)
+        ),
+        Err(Ok(TipError::UntrustedRegistry))
+    );
+    assert!(tipping
+        .try_set_confession_registry(&tipper, &rogue.address)
+        .is_err());
+    assert_eq!(tipping.get_confession_registry(), Some(registry.address));
+}
+
+#[test]
+fn registry_failures_other_than_not_found_surface() {
+    let (env, _registry, badges, tipping, admin, _author) = setup();
+    let token = tipping.get_token().unwrap();
+    let tipper = tipper(&env, &tipping);
+    // A contract without `find_confession` fails the call instead of
+    // reporting a missing confession.
+    tipping.set_confession_registry(&admin, &badges.address);
+
+    let result = tipping.try_tip_confession(
+        &tipper,
+        &badges.address,
+        &1,
+        &token,
+        &TIP_AMOUNT,
+        &refund_commitment(&env, &tipper),
+    );
+    assert!(matches!(result, Err(Err(_))), "got {result:?}");
+}
+
+/// `anonymous_tipping::Confession` mirrors the registry's record so it
+/// decodes across the contract boundary. Both destructurings stop compiling,
+/// and the round trips stop decoding, if either side gains or loses a field.
+#[test]
+fn tipping_confession_mirror_matches_the_registry() {
+    let env = Env::default();
+    let statuses = [
+        (
+            ConfessionStatus::Active,
+            anonymous_tipping::ConfessionStatus::Active,
+        ),
+        (
+            ConfessionStatus::Deleted,
+            anonymous_tipping::ConfessionStatus::Deleted,
+        ),
+        (
+            ConfessionStatus::Flagged,
+            anonymous_tipping::ConfessionStatus::Flagged,
+        ),
+        (
+            ConfessionStatus::Expired,
+            anonymous_tipping::ConfessionStatus::Expired,
+        ),
+    ];
+    for (status, mirrored_status) in statuses {
+        let original = Confession {
+            id: 7,
+            author: Some(Address::generate(&env)),
+            author_commitment: Some(fixture_hash(&env, 0x61)),
+            content_hash: fixture_hash(&env, 0x62),
+            created_at: TS_CREATE,
+            updated_at: TS_FLAG,
+            status,
+            expires_at: Some(TS_FLAG + 1),
+            labels: vec![&env, Symbol::new(&env, "nsfw")],
+        };
+        let val: Val = original.clone().into_val(&env);
+        let anonymous_tipping::Confession {
+            id,
+            author,
+            author_commitment,
+            content_hash,
+            created_at,
+            updated_at,
+            status,
+            expires_at,
+            labels,
+        } = anonymous_tipping::Confession::try_from_val(&env, &val).unwrap();
+        assert_eq!(status, mirrored_status);
+
+        let mirrored = anonymous_tipping::Confession {
+            id,
+            author,
+            author_commitment,
+            content_hash,
+            created_at,
+            updated_at,
+            status,
+            expires_at,
+            labels,
+        };
+        let val: Val = mirrored.into_val(&env);
+        let Confession {
+            id,
+            author,
+            author_commitment,
+            content_hash,
+            created_at,
+            updated_at,
+            status,
+            expires_at,
+            labels,
+        } = Confession::try_from_val(&env, &val).unwrap();
+        let round_tripped = Confession {
+            id,
+            author,
+            author_commitment,
+            content_hash,
+            created_at,
+            updated_at,
+            status,
+            expires_at,
+            labels,
+        };
+        assert_eq!(round_tripped, original);
+    }
+}
@@ -24,8 +24,21 @@ pub fn get_admin(env: &Env) -> Address {
 ///
 /// This intentionally shares the same authorization surface as the rest of the
 /// contract (owner OR admin) so emergency pause cannot be stranded behind a
-/// separate, drift-prone "pause admin" key.
+/// separate, drift-prone "pause admin" key. Guardians may pause as well.
 pub fn require_pause_authority(env: &Env, caller: &Address) -> Result<Address, PauseError> {
+    caller.require_auth();
+
+    let authorized = crate::access_control::is_authorized(env, caller)
+        .map_err(|_| PauseError::Unauthorized)?
+        || crate::access_control::is_guardian(env, caller);
+    if !authorized {
+        return Err(PauseError::Unauthorized);
+    }
+    Ok(caller.clone())
+}
+
+/// Require owner or admin for lifting a pause. Guardians are excluded.
+pub fn require_unpause_authority(env: &Env, caller: &Address) -> Result<Address, PauseError> {
     crate::access_control::require_admin_or_owner(env, caller)
         .map_err(|_| PauseError::Unauthorized)?;
     Ok(caller.clone())
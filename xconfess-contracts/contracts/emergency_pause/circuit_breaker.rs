@@ -0,0 +1,146 @@
+//! Automatic circuit breaker for write spikes.
+//!
+//! Each contract keeps its own `BreakerConfig`. Writes are counted per metric
+//! in fixed ledger windows; when a count goes above its threshold the breaker
+//! emits `circuit_breaker_tripped` and tells the caller to pause. The write
+//! that crosses the threshold still completes, because failing it would also
+//! roll back the pause.
+//!
+//! This file only depends on `soroban_sdk` so contracts that do not use the
+//! shared pause storage can include it on its own.
+
+use soroban_sdk::{contractevent, contracttype, Env};
+
+/// Write volume the breaker watches.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BreakerMetric {
+    Anchors,
+    Confessions,
+    Tips,
+    /// Reserved for "more than Y reports against one confession". Unused
+    /// until a contract has a report entrypoint; reports are handled
+    /// off-chain today.
+    ReportsPerConfession,
+}
+
+/// Per-contract breaker thresholds. A threshold of 0 disables that check.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BreakerConfig {
+    /// Most anchors, confessions or tips allowed in one window.
+    pub max_writes_per_window: u32,
+    /// Window length in ledgers.
+    pub window_ledgers: u32,
+    /// How long a trip pauses the affected operations.
+    pub trip_duration_seconds: u64,
+}
+
+impl BreakerConfig {
+    /// All-zero configs (breaker off) are valid. Otherwise the window must be
+    /// non-empty and the trip duration within `1..=max_trip_seconds`.
+    pub fn is_valid(&self, max_trip_seconds: u64) -> bool {
+        if self.max_writes_per_window == 0 {
+            return true;
+        }
+        self.window_ledgers > 0
+            && self.trip_duration_seconds > 0
+            && self.trip_duration_seconds <= max_trip_seconds
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BreakerWindow {
+    /// Ledger sequence the window opened at.
+    pub window_start: u32,
+    pub count: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum BreakerKey {
+    Config,
+    Window(BreakerMetric),
+}
+
+#[contractevent(topics = ["circuit_breaker_tripped"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerTrippedEvent {
+    #[topic]
+    pub metric: BreakerMetric,
+    pub count: u32,
+    pub threshold: u32,
+    pub window_start: u32,
+    pub trip_duration_seconds: u64,
+}
+
+pub fn get_config(env: &Env) -> BreakerConfig {
+    env.storage()
+        .instance()
+        .get(&BreakerKey::Config)
+        .unwrap_or_default()
+}
+
+/// Store `config`. Callers check `BreakerConfig::is_valid` and authorization.
+pub fn set_config(env: &Env, config: &BreakerConfig) {
+    env.storage().instance().set(&BreakerKey::Config, config);
+}
+
+/// Add one to `window`, starting a new window if the current one has ended.
+fn bump(env: &Env, window: Option<BreakerWindow>, window_ledgers: u32) -> BreakerWindow {
+    let now = env.ledger().sequence();
+    match window {
+        Some(w) if now < w.window_start.saturating_add(window_ledgers) => BreakerWindow {
+            window_start: w.window_start,
+            count: w.count.saturating_add(1),
+        },
+        _ => BreakerWindow {
+            window_start: now,
+            count: 1,
+        },
+    }
+}
+
+fn trip(
+    env: &Env,
+    config: &BreakerConfig,
+    metric: BreakerMetric,
+    window: &BreakerWindow,
+    threshold: u32,
+) {
+    CircuitBreakerTrippedEvent {
+        metric,
+        count: window.count,
+        threshold,
+        window_start: window.window_start,
+        trip_duration_seconds: config.trip_duration_seconds,
+    }
+    .publish(env);
+}
+
+/// Count one anchor, confession or tip write. Returns `true` if this write
+/// tripped the breaker; the caller then applies the pause. The window is
+/// reset on a trip so writes after the pause lapses start from zero.
+pub fn record_write(env: &Env, metric: BreakerMetric) -> bool {
+    let config = get_config(env);
+    let threshold = config.max_writes_per_window;
+    if threshold == 0 {
+        return false;
+    }
+
+    let key = BreakerKey::Window(metric);
+    let window = bump(
+        env,
+        env.storage().instance().get(&key),
+        config.window_ledgers,
+    );
+    if window.count <= threshold {
+        env.storage().instance().set(&key, &window);
+        return false;
+    }
+
+    trip(env, &config, metric, &window, threshold);
+    env.storage().instance().remove(&key);
+    true
+}
@@ -8,4 +8,7 @@ pub enum PauseError {
     NotPaused = 2,
     Unauthorized = 3,
     ContractPaused = 4,
+    InvalidDuration = 5,
+    InvalidBreakerConfig = 6,
+    GovernancePause = 7,
 }
@@ -1,5 +1,7 @@
 use soroban_sdk::{contractevent, Address, Env, String};
 
+use crate::emergency_pause::scope::PauseScope;
+
 pub const MAX_PAUSE_REASON_LEN: u32 = 128;
 pub const PAUSE_REASON_TOO_LONG: &str = "pause reason too long";
 
@@ -19,6 +21,26 @@ pub struct UnpausedEvent {
     pub reason: String,
 }
 
+#[contractevent(topics = ["scope_paused"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopePausedEvent {
+    #[topic]
+    pub scope: PauseScope,
+    #[topic]
+    pub actor: Address,
+    pub reason: String,
+}
+
+#[contractevent(topics = ["scope_unpaused"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopeUnpausedEvent {
+    #[topic]
+    pub scope: PauseScope,
+    #[topic]
+    pub actor: Address,
+    pub reason: String,
+}
+
 fn assert_reason_bounded(reason: &String) {
     if reason.len() > MAX_PAUSE_REASON_LEN {
         panic!("{}", PAUSE_REASON_TOO_LONG);
@@ -35,6 +57,28 @@ pub fn emit_paused(env: &Env, actor: &Address, reason: String) {
     .publish(env);
 }
 
+pub fn emit_scope_paused(env: &Env, scope: PauseScope, actor: &Address, reason: String) {
+    assert_reason_bounded(&reason);
+
+    ScopePausedEvent {
+        scope,
+        actor: actor.clone(),
+        reason,
+    }
+    .publish(env);
+}
+
+pub fn emit_scope_unpaused(env: &Env, scope: PauseScope, actor: &Address, reason: String) {
+    assert_reason_bounded(&reason);
+
+    ScopeUnpausedEvent {
+        scope,
+        actor: actor.clone(),
+        reason,
+    }
+    .publish(env);
+}
+
 pub fn emit_unpaused(env: &Env, actor: &Address, reason: String) {
     assert_reason_bounded(&reason);
 
@@ -0,0 +1,99 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::emergency_pause::{scope::PauseScope, storage::DataKey};
+
+/// Maximum page size for `pause_history`.
+pub const MAX_PAUSE_HISTORY_PAGE_LIMIT: u32 = 50;
+
+/// What a recorded pause applied to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PauseTarget {
+    Global,
+    Scope(PauseScope),
+}
+
+/// Whether a record starts or lifts a pause.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseAction {
+    Pause,
+    Unpause,
+}
+
+/// Who set a pause. Only governance can lift a governance pause early.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseOrigin {
+    /// An owner, admin or guardian calling `pause`/`pause_scope`.
+    Direct,
+    /// A quorum-approved governance proposal.
+    Governance,
+    /// The circuit breaker tripping a scope.
+    CircuitBreaker,
+}
+
+/// One pause or unpause action, kept for audit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseRecord {
+    pub target: PauseTarget,
+    pub action: PauseAction,
+    pub origin: PauseOrigin,
+    pub actor: Address,
+    pub reason: String,
+    /// Ledger timestamp of the action.
+    pub at: u64,
+    /// When the pause lapses; `None` for unpauses.
+    pub expires_at: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseHistoryPage {
+    pub items: Vec<PauseRecord>,
+    /// Index to pass as `cursor` for the next page.
+    pub next_cursor: Option<u64>,
+}
+
+pub fn record_pause(env: &Env, record: &PauseRecord) {
+    let count: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PauseHistoryCount)
+        .unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PauseHistory(count), record);
+    env.storage()
+        .instance()
+        .set(&DataKey::PauseHistoryCount, &(count + 1));
+}
+
+/// Pause actions in the order they happened, starting at index `cursor`.
+pub fn pause_history(env: &Env, cursor: Option<u64>, limit: u32) -> PauseHistoryPage {
+    let limit = limit.clamp(1, MAX_PAUSE_HISTORY_PAGE_LIMIT) as u64;
+    let count: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PauseHistoryCount)
+        .unwrap_or(0);
+    let start = cursor.unwrap_or(0).min(count);
+    let end = start.saturating_add(limit).min(count);
+
+    let mut items = Vec::new(env);
+    for index in start..end {
+        if let Some(record) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PauseHistory(index))
+        {
+            items.push_back(record);
+        }
+    }
+
+    PauseHistoryPage {
+        items,
+        next_cursor: if end < count { Some(end) } else { None },
+    }
+}
@@ -1,10 +1,26 @@
 pub mod admin;
+pub mod circuit_breaker;
 pub mod errors;
 pub mod events;
+pub mod history;
 pub mod pause;
+pub mod scope;
 pub mod storage;
 
 #[allow(unused_imports)]
 pub use admin::{get_admin, set_admin};
 #[allow(unused_imports)]
-pub use pause::{assert_not_paused, is_paused, pause, set_paused_internal, unpause};
+pub use circuit_breaker::{BreakerConfig, BreakerMetric};
+#[allow(unused_imports)]
+pub use history::{
+    pause_history, PauseAction, PauseHistoryPage, PauseOrigin, PauseRecord, PauseTarget,
+};
+#[allow(unused_imports)]
+pub use pause::{
+    assert_not_paused, configure_circuit_breaker, get_pause_state, governance_pause,
+    governance_unpause, is_paused, is_scope_paused, pause, pause_as, pause_scope,
+    record_breaker_write, unpause, unpause_as, unpause_scope, PauseState,
+    MAX_PAUSE_DURATION_SECONDS,
+};
+#[allow(unused_imports)]
+pub use scope::PauseScope;
@@ -1,56 +1,343 @@
-use soroban_sdk::{Env, String};
+use soroban_sdk::{contracttype, Address, Env, String};
 
 use crate::emergency_pause::{
-    admin::require_pause_authority,
+    admin::{require_pause_authority, require_unpause_authority},
+    circuit_breaker::{self, BreakerMetric},
     errors::PauseError,
-    events::{emit_paused, emit_unpaused},
+    events::{emit_paused, emit_scope_paused, emit_scope_unpaused, emit_unpaused},
+    history::{record_pause, PauseAction, PauseOrigin, PauseRecord, PauseTarget},
+    scope::PauseScope,
     storage::DataKey,
 };
 
-pub fn is_paused(env: &Env) -> bool {
+/// Longest a single pause may last before it lapses on its own (7 days).
+pub const MAX_PAUSE_DURATION_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Current global pause state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseState {
+    pub paused: bool,
+    /// When the active pause lapses; `None` if not paused or if the pause
+    /// predates expiring pauses.
+    pub expires_at: Option<u64>,
+    /// `true` while the active pause was set by governance, which means
+    /// only a governance `Unpause` can lift it early.
+    pub governed: bool,
+}
+
+fn paused_until(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::PausedUntil)
+}
+
+fn pause_origin(env: &Env) -> PauseOrigin {
     env.storage()
+        .instance()
+        .get(&DataKey::PauseOrigin)
+        .unwrap_or(PauseOrigin::Direct)
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    let flagged: bool = env
+        .storage()
         .instance()
         .get(&DataKey::Paused)
-        .unwrap_or(false)
+        .unwrap_or(false);
+    flagged && paused_until(env).is_none_or(|until| env.ledger().timestamp() < until)
 }
 
-pub fn assert_not_paused(env: &Env) -> Result<(), PauseError> {
-    if is_paused(env) {
+pub fn get_pause_state(env: &Env) -> PauseState {
+    let paused = is_paused(env);
+    PauseState {
+        paused,
+        expires_at: if paused { paused_until(env) } else { None },
+        governed: paused && pause_origin(env) == PauseOrigin::Governance,
+    }
+}
+
+fn scope_paused_until(env: &Env, scope: PauseScope) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get::<DataKey, u64>(&DataKey::ScopePaused(scope))
+        .filter(|until| env.ledger().timestamp() < *until)
+}
+
+/// `true` if `scope` is paused on its own or the global pause is active.
+pub fn is_scope_paused(env: &Env, scope: PauseScope) -> bool {
+    is_paused(env) || scope_paused_until(env, scope).is_some()
+}
+
+/// Fail with `ContractPaused` if `scope` (or the whole contract) is paused.
+pub fn assert_not_paused(env: &Env, scope: PauseScope) -> Result<(), PauseError> {
+    if is_scope_paused(env, scope) {
         return Err(PauseError::ContractPaused);
     }
     Ok(())
 }
 
-pub fn pause(env: Env, caller: soroban_sdk::Address, reason: String) -> Result<(), PauseError> {
+fn expiry_for(env: &Env, duration_seconds: u64) -> Result<u64, PauseError> {
+    if duration_seconds == 0 || duration_seconds > MAX_PAUSE_DURATION_SECONDS {
+        return Err(PauseError::InvalidDuration);
+    }
+    Ok(env.ledger().timestamp() + duration_seconds)
+}
+
+/// Store the global pause and add it to the history.
+fn set_global_pause(
+    env: &Env,
+    actor: Address,
+    origin: PauseOrigin,
+    reason: String,
+    expires_at: Option<u64>,
+) {
+    env.storage().instance().set(&DataKey::Paused, &true);
+    env.storage().instance().set(&DataKey::PauseOrigin, &origin);
+    match expires_at {
+        Some(until) => env.storage().instance().set(&DataKey::PausedUntil, &until),
+        None => env.storage().instance().remove(&DataKey::PausedUntil),
+    }
+
+    emit_paused(env, &actor, reason.clone());
+    record_pause(
+        env,
+        &PauseRecord {
+            target: PauseTarget::Global,
+            action: PauseAction::Pause,
+            origin,
+            actor,
+            reason,
+            at: env.ledger().timestamp(),
+            expires_at,
+        },
+    );
+}
+
+/// Clear the global pause and add the early lift to the history.
+fn clear_global_pause(env: &Env, actor: Address, origin: PauseOrigin, reason: String) {
+    env.storage().instance().set(&DataKey::Paused, &false);
+    env.storage().instance().remove(&DataKey::PausedUntil);
+    env.storage().instance().remove(&DataKey::PauseOrigin);
+
+    emit_unpaused(env, &actor, reason.clone());
+    record_pause(
+        env,
+        &PauseRecord {
+            target: PauseTarget::Global,
+            action: PauseAction::Unpause,
+            origin,
+            actor,
+            reason,
+            at: env.ledger().timestamp(),
+            expires_at: None,
+        },
+    );
+}
+
+/// Pause the whole contract for `duration_seconds` (owner, admin or guardian).
+pub fn pause(
+    env: Env,
+    caller: Address,
+    reason: String,
+    duration_seconds: u64,
+) -> Result<(), PauseError> {
     let actor = require_pause_authority(&env, &caller)?;
+    pause_as(&env, actor, reason, duration_seconds)
+}
 
-    if is_paused(&env) {
+/// Body of `pause` for contracts that authorize `actor` themselves.
+pub fn pause_as(
+    env: &Env,
+    actor: Address,
+    reason: String,
+    duration_seconds: u64,
+) -> Result<(), PauseError> {
+    let expires_at = expiry_for(env, duration_seconds)?;
+
+    if is_paused(env) {
         return Err(PauseError::AlreadyPaused);
     }
 
-    env.storage().instance().set(&DataKey::Paused, &true);
+    set_global_pause(env, actor, PauseOrigin::Direct, reason, Some(expires_at));
+    Ok(())
+}
+
+/// Lift the global pause before it lapses (owner or admin only). A pause set
+/// by governance can only be lifted by governance.
+pub fn unpause(env: Env, caller: Address, reason: String) -> Result<(), PauseError> {
+    let actor = require_unpause_authority(&env, &caller)?;
+    unpause_as(&env, actor, reason)
+}
 
-    emit_paused(&env, &actor, reason);
+/// Body of `unpause` for contracts that authorize `actor` themselves.
+pub fn unpause_as(env: &Env, actor: Address, reason: String) -> Result<(), PauseError> {
+    if !is_paused(env) {
+        return Err(PauseError::NotPaused);
+    }
+    if pause_origin(env) == PauseOrigin::Governance {
+        return Err(PauseError::GovernancePause);
+    }
 
+    clear_global_pause(env, actor, PauseOrigin::Direct, reason);
     Ok(())
 }
 
-pub fn unpause(env: Env, caller: soroban_sdk::Address, reason: String) -> Result<(), PauseError> {
-    let actor = require_pause_authority(&env, &caller)?;
+/// Pause a single operation scope for `duration_seconds`. The global pause
+/// is unaffected.
+pub fn pause_scope(
+    env: &Env,
+    caller: &Address,
+    scope: PauseScope,
+    reason: String,
+    duration_seconds: u64,
+) -> Result<(), PauseError> {
+    let actor = require_pause_authority(env, caller)?;
+    let expires_at = expiry_for(env, duration_seconds)?;
+
+    if scope_paused_until(env, scope).is_some() {
+        return Err(PauseError::AlreadyPaused);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ScopePaused(scope), &expires_at);
 
-    if !is_paused(&env) {
+    emit_scope_paused(env, scope, &actor, reason.clone());
+    record_pause(
+        env,
+        &PauseRecord {
+            target: PauseTarget::Scope(scope),
+            action: PauseAction::Pause,
+            origin: PauseOrigin::Direct,
+            actor,
+            reason,
+            at: env.ledger().timestamp(),
+            expires_at: Some(expires_at),
+        },
+    );
+
+    Ok(())
+}
+
+/// Lift a scope pause (owner or admin only). Does not lift an active
+/// global pause.
+pub fn unpause_scope(
+    env: &Env,
+    caller: &Address,
+    scope: PauseScope,
+    reason: String,
+) -> Result<(), PauseError> {
+    let actor = require_unpause_authority(env, caller)?;
+
+    if scope_paused_until(env, scope).is_none() {
         return Err(PauseError::NotPaused);
     }
 
-    env.storage().instance().set(&DataKey::Paused, &false);
+    env.storage()
+        .instance()
+        .remove(&DataKey::ScopePaused(scope));
+
+    emit_scope_unpaused(env, scope, &actor, reason.clone());
+    record_pause(
+        env,
+        &PauseRecord {
+            target: PauseTarget::Scope(scope),
+            action: PauseAction::Unpause,
+            origin: PauseOrigin::Direct,
+            actor,
+            reason,
+            at: env.ledger().timestamp(),
+            expires_at: None,
+        },
+    );
+
+    Ok(())
+}
+
+/// Internal: pause the whole contract without an authorization check. Used
+/// by the governance module after quorum approval, with `actor` as the
+/// executor. The pause lapses after `duration_seconds`, which is capped at
+/// `MAX_PAUSE_DURATION_SECONDS` like a direct pause, unless governance lifts
+/// it first. Replaces any direct pause already active.
+pub fn governance_pause(
+    env: &Env,
+    actor: &Address,
+    duration_seconds: u64,
+) -> Result<(), PauseError> {
+    let expires_at = expiry_for(env, duration_seconds)?;
+
+    set_global_pause(
+        env,
+        actor.clone(),
+        PauseOrigin::Governance,
+        String::from_str(env, "governance"),
+        Some(expires_at),
+    );
+    Ok(())
+}
 
-    emit_unpaused(&env, &actor, reason);
+/// Internal: lift the global pause, whoever set it, after quorum approval.
+pub fn governance_unpause(env: &Env, actor: &Address) -> Result<(), PauseError> {
+    if !is_paused(env) {
+        return Err(PauseError::NotPaused);
+    }
 
+    clear_global_pause(
+        env,
+        actor.clone(),
+        PauseOrigin::Governance,
+        String::from_str(env, "governance"),
+    );
     Ok(())
 }
 
-/// Internal: Set paused state without admin authorization check.
-/// Used by governance module after quorum approval.
-pub fn set_paused_internal(env: &Env, paused: bool) {
-    env.storage().instance().set(&DataKey::Paused, &paused);
+/// Internal: pause `scope` on behalf of the circuit breaker. The contract
+/// itself is recorded as the actor. A scope that is already paused is left
+/// as is.
+fn trip_scope(env: &Env, scope: PauseScope, duration_seconds: u64) {
+    if scope_paused_until(env, scope).is_some() {
+        return;
+    }
+
+    let actor = env.current_contract_address();
+    let reason = String::from_str(env, "circuit breaker");
+    let expires_at = env.ledger().timestamp() + duration_seconds;
+    env.storage()
+        .instance()
+        .set(&DataKey::ScopePaused(scope), &expires_at);
+
+    emit_scope_paused(env, scope, &actor, reason.clone());
+    record_pause(
+        env,
+        &PauseRecord {
+            target: PauseTarget::Scope(scope),
+            action: PauseAction::Pause,
+            origin: PauseOrigin::CircuitBreaker,
+            actor,
+            reason,
+            at: env.ledger().timestamp(),
+            expires_at: Some(expires_at),
+        },
+    );
+}
+
+/// Count a write against the circuit breaker and pause `scope` if it trips.
+pub fn record_breaker_write(env: &Env, metric: BreakerMetric, scope: PauseScope) {
+    if circuit_breaker::record_write(env, metric) {
+        let duration = circuit_breaker::get_config(env).trip_duration_seconds;
+        trip_scope(env, scope, duration);
+    }
+}
+
+/// Validate and store the circuit breaker thresholds (owner or admin only).
+pub fn configure_circuit_breaker(
+    env: &Env,
+    caller: &Address,
+    config: &circuit_breaker::BreakerConfig,
+) -> Result<(), PauseError> {
+    require_unpause_authority(env, caller)?;
+    if !config.is_valid(MAX_PAUSE_DURATION_SECONDS) {
+        return Err(PauseError::InvalidBreakerConfig);
+    }
+    circuit_breaker::set_config(env, config);
+    Ok(())
 }
@@ -0,0 +1,17 @@
+use soroban_sdk::contracttype;
+
+/// Operation groups that can be paused independently of the global pause.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    Create,
+    Update,
+    Delete,
+    Anchor,
+    /// Tip settlement in anonymous-tipping: direct, commitment and
+    /// confession tips, subscriptions and their collection.
+    Tip,
+    /// Reserved for an on-chain report path. Reports are handled off-chain
+    /// today, so no contract checks this scope yet.
+    Report,
+}
@@ -1,8 +1,21 @@
 use soroban_sdk::contracttype;
 
+use crate::emergency_pause::scope::PauseScope;
+
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Admin,
     Paused,
+    /// Ledger timestamp at which the global pause lapses. Absent for pauses
+    /// set before expiring pauses existed, which stay until unpaused.
+    PausedUntil,
+    /// `PauseOrigin` of the active global pause. Absent for pauses set before
+    /// origins were recorded, which count as direct pauses.
+    PauseOrigin,
+    /// Ledger timestamp at which a scope pause lapses.
+    ScopePaused(PauseScope),
+    PauseHistoryCount,
+    /// Pause history entry by index (persistent storage).
+    PauseHistory(u64),
 }
@@ -159,10 +159,20 @@ pub fn execute(e: &Env, executor: Address, id: u64) {
                 .unwrap_or_else(|err| panic!("{}", err as u32));
         }
         CriticalAction::Pause => {
-            emergency_pause::set_paused_internal(e, true);
+            emergency_pause::governance_pause(
+                e,
+                &executor,
+                emergency_pause::MAX_PAUSE_DURATION_SECONDS,
+            )
+            .unwrap_or_else(|err| panic!("{}", err as u32));
+        }
+        CriticalAction::PauseFor(duration_seconds) => {
+            emergency_pause::governance_pause(e, &executor, duration_seconds)
+                .unwrap_or_else(|err| panic!("{}", err as u32));
         }
         CriticalAction::Unpause => {
-            emergency_pause::set_paused_internal(e, false);
+            emergency_pause::governance_unpause(e, &executor)
+                .unwrap_or_else(|err| panic!("{}", err as u32));
         }
     }
 
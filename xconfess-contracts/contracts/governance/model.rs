@@ -6,7 +6,12 @@ pub enum CriticalAction {
     GrantAdmin(Address),
     RevokeAdmin(Address),
     TransferOwnership(Address),
+    /// Pause for `MAX_PAUSE_DURATION_SECONDS`, or until a later `Unpause`
+    /// proposal executes.
     Pause,
+    /// Pause for the given number of seconds, at most
+    /// `MAX_PAUSE_DURATION_SECONDS`.
+    PauseFor(u64),
     Unpause,
 }
 
@@ -20,6 +20,7 @@
 
 use soroban_sdk::{
     testutils::{Address as _, Events},
+    token::StellarAssetClient,
     Address, BytesN, Env, IntoVal, String as SorobanString,
 };
 
@@ -248,7 +249,10 @@ pub fn verify_anchor_fixture(env: &Env, client: &ConfessionAnchorClient, fixture
 
 /// Execute tip settlement fixture and verify event emission
 pub fn verify_tip_fixture(env: &Env, client: &AnonymousTippingClient, fixture: &TipSettlementFixture) {
+    let sender = Address::generate(env);
     let recipient = Address::generate(env);
+    let token = client.get_token().unwrap();
+    StellarAssetClient::new(env, &token).mint(&sender, &fixture.amount);
     let proof = if fixture.proof_present {
         Some(SorobanString::from_str(env, fixture.proof_metadata))
     } else {
@@ -256,7 +260,7 @@ pub fn verify_tip_fixture(env: &Env, client: &AnonymousTippingClient, fixture: &
     };
 
     let settlement_id = client
-        .send_tip_with_proof(&recipient, &fixture.amount, &proof)
+        .send_tip_with_proof(&sender, &recipient, &token, &fixture.amount, &proof)
         .expect(&format!("fixture tip must succeed: {}", fixture.description));
 
     assert_eq!(
@@ -276,10 +280,15 @@ pub fn verify_tip_fixture(env: &Env, client: &AnonymousTippingClient, fixture: &
     // Find the settlement event (last event should be the tip settlement)
     let (_contract_id, _topics, data) = events.last().unwrap();
 
-    // SettlementEvent structure: (recipient, event_version, settlement_id, amount, proof_metadata, proof_present, timestamp)
+    // SettlementEvent structure: (token, event_version, settlement_id, amount, proof_metadata, proof_present, timestamp)
     // We verify the key fields that backend relies on
     let decoded: (Address, u32, u64, i128, SorobanString, bool, u64) = data.into_val(env);
 
+    assert_eq!(
+        decoded.0, token,
+        "token mismatch for fixture: {}",
+        fixture.description
+    );
     assert_eq!(
         decoded.1, fixture.event_version,
         "event version mismatch for fixture: {}",
@@ -320,7 +329,8 @@ mod tests {
         env.mock_all_auths();
         let contract_id = env.register(AnonymousTipping, ());
         let client = AnonymousTippingClient::new(env, &contract_id);
-        client.init();
+        let token_admin = Address::generate(env);
+        client.init(&env.register_stellar_asset_contract_v2(token_admin).address());
         client
     }
 
@@ -4,7 +4,7 @@ use anonymous_tipping::AnonymousTipping;
 use soroban_sdk::testutils::Address as _;
 use confession_anchor::{ConfessionAnchor, ConfessionAnchorClient};
 use confession_registry::{
-    ConfessionRegistry, ConfessionRegistryClient, MAX_AUTHOR_CONFESSIONS_PER_AUTHOR,
+    ConfessionRegistry, ConfessionRegistryClient, DEFAULT_MAX_CONFESSIONS_PER_WINDOW,
 };
 
 #[test]
@@ -35,7 +35,9 @@ fn confession_content_limit_plus_one_rejected() {
 fn settlement_proof_metadata_exact_limit_succeeds() {
     let env = Env::default();
     let recipient = soroban_sdk::Address::generate(&env);
-    AnonymousTipping::init(env.clone());
+    let sender = soroban_sdk::Address::generate(&env);
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
 
     let metadata = SorobanString::from_str(
         &env,
@@ -43,13 +45,15 @@ fn settlement_proof_metadata_exact_limit_succeeds() {
     );
     let settlement_id = AnonymousTipping::send_tip_with_proof(
         env.clone(),
+        sender.clone(),
         recipient.clone(),
+        token.clone(),
         10,
         Some(metadata),
     );
 
     assert_eq!(settlement_id, 1);
-    assert_eq!(AnonymousTipping::get_tips(env, recipient), 10);
+    assert_eq!(AnonymousTipping::get_tips(env, recipient, token), 10);
 }
 
 #[test]
@@ -57,14 +61,16 @@ fn settlement_proof_metadata_exact_limit_succeeds() {
 fn settlement_proof_metadata_limit_plus_one_rejected() {
     let env = Env::default();
     let recipient = soroban_sdk::Address::generate(&env);
-    AnonymousTipping::init(env.clone());
+    let sender = soroban_sdk::Address::generate(&env);
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
 
     let metadata = SorobanString::from_str(
         &env,
         &"p".repeat((AnonymousTipping::MAX_PROOF_METADATA_LEN + 1) as usize),
     );
 
-    let _ = AnonymousTipping::send_tip_with_proof(env, recipient, 10, Some(metadata));
+    let _ = AnonymousTipping::send_tip_with_proof(env, sender.clone(), recipient, token.clone(), 10, Some(metadata));
 }
 
 #[test]
@@ -78,7 +84,7 @@ fn anchor_pause_reason_exact_limit_succeeds() {
 
     client.initialize(&owner);
 
-    client.pause(&owner, &reason);
+    client.pause(&owner, &reason, &3_600);
 }
 
 #[test]
@@ -93,11 +99,11 @@ fn anchor_pause_reason_limit_plus_one_rejected() {
 
     client.initialize(&owner);
 
-    let _ = client.pause(&owner, &reason);
+    let _ = client.pause(&owner, &reason, &3_600);
 }
 
 #[test]
-fn registry_author_index_exact_limit_succeeds() {
+fn registry_author_rate_limit_exact_limit_succeeds() {
     let env = Env::default();
     env.mock_all_auths();
     let contract_id = env.register(ConfessionRegistry, ());
@@ -107,18 +113,18 @@ fn registry_author_index_exact_limit_succeeds() {
 
     client.initialize(&admin);
 
-    for seed in 0..MAX_AUTHOR_CONFESSIONS_PER_AUTHOR {
+    for seed in 0..DEFAULT_MAX_CONFESSIONS_PER_WINDOW {
         let mut bytes = [0u8; 32];
         bytes[0] = seed as u8;
         let hash = soroban_sdk::BytesN::from_array(&env, &bytes);
-        let id = client.create_confession(&author, &hash, &(1_000 + seed as u64));
+        let id = client.create_confession(&author, &hash, &(1_000 + seed as u64), &None);
         assert_eq!(id, seed as u64 + 1);
     }
 }
 
 #[test]
-#[should_panic(expected = "registry payload too long")]
-fn registry_author_index_limit_plus_one_rejected() {
+#[should_panic(expected = "author rate limit exceeded")]
+fn registry_author_rate_limit_plus_one_rejected() {
     let env = Env::default();
     env.mock_all_auths();
     let contract_id = env.register(ConfessionRegistry, ());
@@ -128,11 +134,11 @@ fn registry_author_index_limit_plus_one_rejected() {
 
     client.initialize(&admin);
 
-    for seed in 0..=MAX_AUTHOR_CONFESSIONS_PER_AUTHOR {
+    for seed in 0..=DEFAULT_MAX_CONFESSIONS_PER_WINDOW {
         let mut bytes = [0u8; 32];
         bytes[0] = seed as u8;
         let hash = soroban_sdk::BytesN::from_array(&env, &bytes);
-        let _ = client.create_confession(&author, &hash, &(1_000 + seed as u64));
+        let _ = client.create_confession(&author, &hash, &(1_000 + seed as u64), &None);
     }
 }
 
@@ -145,48 +151,58 @@ fn registry_author_index_limit_plus_one_rejected() {
 #[should_panic(expected = "tip amount must be positive")]
 fn amount_zero_rejected() {
     let env = Env::default();
-    AnonymousTipping::init(env.clone());
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
     let recipient = soroban_sdk::Address::generate(&env);
-    let _ = AnonymousTipping::send_tip(env, recipient, 0);
+    let sender = soroban_sdk::Address::generate(&env);
+    let _ = AnonymousTipping::send_tip(env, sender.clone(), recipient, token.clone(), 0);
 }
 
 #[test]
 #[should_panic(expected = "tip amount must be positive")]
 fn amount_negative_one_rejected() {
     let env = Env::default();
-    AnonymousTipping::init(env.clone());
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
     let recipient = soroban_sdk::Address::generate(&env);
-    let _ = AnonymousTipping::send_tip(env, recipient, -1);
+    let sender = soroban_sdk::Address::generate(&env);
+    let _ = AnonymousTipping::send_tip(env, sender.clone(), recipient, token.clone(), -1);
 }
 
 #[test]
 #[should_panic(expected = "tip amount must be positive")]
 fn amount_i128_min_rejected() {
     let env = Env::default();
-    AnonymousTipping::init(env.clone());
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
     let recipient = soroban_sdk::Address::generate(&env);
-    let _ = AnonymousTipping::send_tip(env, recipient, i128::MIN);
+    let sender = soroban_sdk::Address::generate(&env);
+    let _ = AnonymousTipping::send_tip(env, sender.clone(), recipient, token.clone(), i128::MIN);
 }
 
 #[test]
 fn amount_one_accepted() {
     let env = Env::default();
-    AnonymousTipping::init(env.clone());
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
     let recipient = soroban_sdk::Address::generate(&env);
-    let id = AnonymousTipping::send_tip(env.clone(), recipient.clone(), 1);
+    let sender = soroban_sdk::Address::generate(&env);
+    let id = AnonymousTipping::send_tip(env.clone(), sender.clone(), recipient.clone(), token.clone(), 1);
     assert_eq!(id, 1);
-    assert_eq!(AnonymousTipping::get_tips(env, recipient), 1);
+    assert_eq!(AnonymousTipping::get_tips(env, recipient, token), 1);
 }
 
 #[test]
 fn amount_half_i128_max_accepted() {
     let env = Env::default();
-    AnonymousTipping::init(env.clone());
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
     let recipient = soroban_sdk::Address::generate(&env);
+    let sender = soroban_sdk::Address::generate(&env);
     let amount = i128::MAX / 2;
-    let id = AnonymousTipping::send_tip(env.clone(), recipient.clone(), amount);
+    let id = AnonymousTipping::send_tip(env.clone(), sender.clone(), recipient.clone(), token.clone(), amount);
     assert_eq!(id, 1);
-    assert_eq!(AnonymousTipping::get_tips(env, recipient), amount);
+    assert_eq!(AnonymousTipping::get_tips(env, recipient, token), amount);
 }
 
 // ── Metadata boundary table ───────────────────────────────────────────────────
@@ -194,20 +210,24 @@ fn amount_half_i128_max_accepted() {
 #[test]
 fn metadata_length_64_accepted() {
     let env = Env::default();
-    AnonymousTipping::init(env.clone());
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
     let recipient = soroban_sdk::Address::generate(&env);
+    let sender = soroban_sdk::Address::generate(&env);
     let meta = SorobanString::from_str(&env, &"x".repeat(64));
-    let id = AnonymousTipping::send_tip_with_proof(env.clone(), recipient.clone(), 1, Some(meta));
+    let id = AnonymousTipping::send_tip_with_proof(env.clone(), sender.clone(), recipient.clone(), token.clone(), 1, Some(meta));
     assert_eq!(id, 1);
 }
 
 #[test]
 fn metadata_length_127_accepted() {
     let env = Env::default();
-    AnonymousTipping::init(env.clone());
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
     let recipient = soroban_sdk::Address::generate(&env);
+    let sender = soroban_sdk::Address::generate(&env);
     let meta = SorobanString::from_str(&env, &"x".repeat(127));
-    let id = AnonymousTipping::send_tip_with_proof(env.clone(), recipient.clone(), 1, Some(meta));
+    let id = AnonymousTipping::send_tip_with_proof(env.clone(), sender.clone(), recipient.clone(), token.clone(), 1, Some(meta));
     assert_eq!(id, 1);
 }
 
@@ -215,10 +235,12 @@ fn metadata_length_127_accepted() {
 #[should_panic(expected = "proof metadata too long")]
 fn metadata_length_256_rejected() {
     let env = Env::default();
-    AnonymousTipping::init(env.clone());
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
     let recipient = soroban_sdk::Address::generate(&env);
+    let sender = soroban_sdk::Address::generate(&env);
     let meta = SorobanString::from_str(&env, &"x".repeat(256));
-    let _ = AnonymousTipping::send_tip_with_proof(env, recipient, 1, Some(meta));
+    let _ = AnonymousTipping::send_tip_with_proof(env, sender.clone(), recipient, token.clone(), 1, Some(meta));
 }
 
 // ── Duplicate settlement / accumulated totals ─────────────────────────────────
@@ -229,16 +251,18 @@ fn metadata_length_256_rejected() {
 #[test]
 fn duplicate_recipient_tips_accumulate_and_id_increments() {
     let env = Env::default();
-    AnonymousTipping::init(env.clone());
+    let token = soroban_sdk::Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
     let recipient = soroban_sdk::Address::generate(&env);
+    let sender = soroban_sdk::Address::generate(&env);
 
-    let id1 = AnonymousTipping::send_tip(env.clone(), recipient.clone(), 10);
-    let id2 = AnonymousTipping::send_tip(env.clone(), recipient.clone(), 20);
-    let id3 = AnonymousTipping::send_tip(env.clone(), recipient.clone(), 30);
+    let id1 = AnonymousTipping::send_tip(env.clone(), sender.clone(), recipient.clone(), token.clone(), 10);
+    let id2 = AnonymousTipping::send_tip(env.clone(), sender.clone(), recipient.clone(), token.clone(), 20);
+    let id3 = AnonymousTipping::send_tip(env.clone(), sender.clone(), recipient.clone(), token.clone(), 30);
 
     assert_eq!(id1, 1);
     assert_eq!(id2, 2);
     assert_eq!(id3, 3);
-    assert_eq!(AnonymousTipping::get_tips(env.clone(), recipient), 60);
+    assert_eq!(AnonymousTipping::get_tips(env.clone(), recipient, token), 60);
     assert_eq!(AnonymousTipping::latest_settlement_nonce(env), 3);
 }
@@ -1,36 +1,153 @@
-use soroban_sdk::{Env, vec};
-use xconfess_contract::checkpoint::{get_checkpoint_summary, get_snapshot_page};
-use xconfess_contract::confession::Confession;
-use xconfess_contract::reaction::Reaction;
-use xconfess_contract::report::Report;
+#![cfg(test)]
+
+use confession_registry::checkpoint::{CheckpointKey, SnapshotKind};
+use confession_registry::{ConfessionRegistry, ConfessionRegistryClient, ConfessionStatus};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+// ─── Helpers ────────────────────────────────────────────────────────────────
+
+fn setup() -> (Env, ConfessionRegistryClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let id = env.register(ConfessionRegistry, ());
+    let client = ConfessionRegistryClient::new(&env, &id);
+    let admin = Address::generate(&env);
+    let author = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, admin, author)
+}
+
+fn hash(env: &Env, seed: u8) -> BytesN<32> {
+    let mut b = [0u8; 32];
+    b[0] = seed;
+    BytesN::from_array(env, &b)
+}
+
+// ─── Tests ───────────────────────────────────────────────────────────────────
 
 #[test]
 fn test_checkpoint_summary() {
-    let env = Env::default();
+    let (env, client, _admin, author) = setup();
+    let first = client.create_confession(&author, &hash(&env, 1), &1, &None);
+    let second = client.create_confession(&author, &hash(&env, 2), &2, &None);
+    let third = client.create_confession(&author, &hash(&env, 3), &3, &None);
+    client.update_status(&author, &first, &ConfessionStatus::Flagged, &4, &None);
+    client.delete_confession(&author, &second, &5, &None);
+    client.erase_confession(&author, &third);
 
-    let confessions = vec![&env, Confession { confession_id: 1, author: env.accounts().next().unwrap(), content_hash: "hash1".into(), timestamp: 1 }];
-    let reactions = vec![&env, Reaction { reaction_id: 1, confession_id: 1, reactor: env.accounts().next().unwrap(), reaction_type: "like".into(), timestamp: 1 }];
-    let reports = vec![&env, Report { report_id: 1, confession_id: 1, reporter: env.accounts().next().unwrap(), reason: "spam".into(), timestamp: 1 }];
+    let summary = client.get_checkpoint();
 
-    let summary = get_checkpoint_summary(&env, &confessions, &reactions, &reports);
+    assert_eq!(summary.counters.total_created, 3);
+    assert_eq!(summary.counters.active, 0);
+    assert_eq!(summary.counters.flagged, 1);
+    assert_eq!(summary.counters.deleted, 1);
+    assert_eq!(summary.counters.erased, 1);
+    assert_eq!(summary.counters.events_emitted, 6);
+    assert_eq!(summary.latest_confession_id, 3);
+    assert_eq!(summary.latest_confession_nonce, 2);
+}
+
+#[test]
+fn checkpoint_digest_tracks_state_only() {
+    let (env, client, _admin, author) = setup();
+    client.create_confession(&author, &hash(&env, 1), &1, &None);
+
+    let before = client.get_checkpoint();
+    assert_eq!(client.get_checkpoint().digest, before.digest);
 
-    assert_eq!(summary.total_confessions, 1);
-    assert_eq!(summary.total_reactions, 1);
-    assert_eq!(summary.total_reports, 1);
-    assert_eq!(summary.latest_confession_id, 1);
+    client.create_confession(&author, &hash(&env, 2), &2, &None);
+    assert_ne!(client.get_checkpoint().digest, before.digest);
 }
 
 #[test]
 fn test_snapshot_page() {
-    let env = Env::default();
-    let confessions = vec![
-        &env, 
-        Confession { confession_id: 1, author: env.accounts().next().unwrap(), content_hash: "hash1".into(), timestamp: 1 },
-        Confession { confession_id: 2, author: env.accounts().next().unwrap(), content_hash: "hash2".into(), timestamp: 2 },
-    ];
-
-    let page = get_snapshot_page(&confessions, 0, 1, |c: &Confession| c.confession_id);
-    assert_eq!(page.items.len(), 1);
-    assert_eq!(page.items[0].confession_id, 1);
+    let (env, client, _admin, author) = setup();
+    for i in 1..=3 {
+        client.create_confession(&author, &hash(&env, i), &(i as u64), &None);
+    }
+
+    let page = client.get_snapshot_page(&SnapshotKind::Confessions, &None, &2);
+    assert_eq!(page.confessions.len(), 2);
+    assert_eq!(page.confessions.get(0).unwrap().id, 1);
     assert_eq!(page.next_cursor, Some(2));
-}
\ No newline at end of file
+
+    let last = client.get_snapshot_page(&SnapshotKind::Confessions, &Some(2), &2);
+    assert_eq!(last.confessions.len(), 1);
+    assert_eq!(last.next_cursor, None);
+}
+
+#[test]
+fn snapshot_page_lists_tombstones_separately() {
+    let (env, client, _admin, author) = setup();
+    let kept = client.create_confession(&author, &hash(&env, 1), &1, &None);
+    let erased = client.create_confession(&author, &hash(&env, 2), &2, &None);
+    client.erase_confession(&author, &erased);
+
+    let confessions = client.get_snapshot_page(&SnapshotKind::Confessions, &None, &10);
+    let tombstones = client.get_snapshot_page(&SnapshotKind::Tombstones, &None, &10);
+
+    assert_eq!(confessions.confessions.len(), 1);
+    assert_eq!(confessions.confessions.get(0).unwrap().id, kept);
+    assert!(confessions.tombstones.is_empty());
+    assert_eq!(tombstones.tombstones.len(), 1);
+    assert_eq!(tombstones.tombstones.get(0).unwrap().id, erased);
+}
+
+#[test]
+fn consumed_caller_nonces_enter_the_digest() {
+    let (env, client, _admin, author) = setup();
+    client.create_confession(&author, &hash(&env, 1), &1, &None);
+    let before = client.get_checkpoint();
+
+    // A rejected nonce changes nothing; a consumed one is counted.
+    assert!(client
+        .try_create_confession_seq(&author, &hash(&env, 2), &2, &0, &None, &None)
+        .is_err());
+    assert_eq!(client.get_checkpoint().digest, before.digest);
+    client.create_confession_seq(&author, &hash(&env, 2), &2, &1, &None, &None);
+    let after = client.get_checkpoint();
+    assert_eq!(after.counters.nonces_consumed, 1);
+    assert_ne!(after.digest, before.digest);
+}
+
+#[test]
+fn rebuild_recounts_counters_missing_after_an_upgrade() {
+    let (env, client, admin, author) = setup();
+    let flagged = client.create_confession(&author, &hash(&env, 1), &1, &None);
+    let deleted = client.create_confession(&author, &hash(&env, 2), &2, &None);
+    let erased = client.create_confession(&author, &hash(&env, 3), &3, &None);
+    let active = client.create_confession(&author, &hash(&env, 4), &4, &None);
+    client.update_status(&author, &flagged, &ConfessionStatus::Flagged, &5, &None);
+    client.delete_confession(&author, &deleted, &6, &None);
+    client.erase_confession(&author, &erased);
+    let expected = client.get_checkpoint().counters;
+
+    // Pre-counter deployments have no counters stored.
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&CheckpointKey::Counters);
+    });
+    assert_eq!(client.get_checkpoint().counters.total_created, 0);
+
+    assert!(client.try_rebuild_checkpoint_counters(&author, &2).is_err());
+    assert_eq!(client.rebuild_checkpoint_counters(&admin, &2), Some(3));
+
+    // Writes during the rebuild, to examined, unexamined and new IDs, are
+    // carried over.
+    client.update_status(&author, &flagged, &ConfessionStatus::Active, &7, &None);
+    client.delete_confession(&author, &active, &8, &None);
+    let created = client.create_confession(&author, &hash(&env, 5), &9, &None);
+    assert_eq!(client.rebuild_checkpoint_counters(&admin, &2), None);
+
+    let rebuilt = client.get_checkpoint().counters;
+    assert_eq!(rebuilt.total_created, expected.total_created + 1);
+    assert_eq!(rebuilt.active, 2);
+    assert_eq!(rebuilt.flagged, 0);
+    assert_eq!(rebuilt.deleted, 2);
+    assert_eq!(rebuilt.erased, 1);
+    assert_eq!(rebuilt.events_emitted, expected.events_emitted + 3);
+    assert_eq!(created, 5);
+
+    // A finished rebuild leaves later writes to the live counters.
+    client.update_status(&author, &created, &ConfessionStatus::Flagged, &10, &None);
+    assert_eq!(client.get_checkpoint().counters.flagged, 1);
+}
@@ -25,7 +25,7 @@ fn hash(env: &Env, seed: u8) -> BytesN<32> {
 /// Seed `n` confessions and return the IDs in insertion order.
 fn seed(client: &ConfessionRegistryClient, env: &Env, author: &Address, n: u8) -> Vec<u64> {
     (0..n)
-        .map(|i| client.create_confession(author, &hash(env, i), &(1_000 + i as u64)))
+        .map(|i| client.create_confession(author, &hash(env, i), &(1_000 + i as u64), &None))
         .collect()
 }
 
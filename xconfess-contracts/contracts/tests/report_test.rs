@@ -78,32 +78,36 @@ fn test_report_reason_over_limit_rejected() {
 #[test]
 fn anonymous_tip_emits_settlement_receipt_event_and_updates_nonce() {
     let env = Env::default();
+    let sender = Address::generate(&env);
     let recipient = Address::generate(&env);
-    AnonymousTipping::init(env.clone());
+    let token = Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
 
     let before_events: std::vec::Vec<_> = env.events().all().collect();
     assert_eq!(AnonymousTipping::latest_settlement_nonce(env.clone()), 0);
 
-    let settlement_id = AnonymousTipping::send_tip(env.clone(), recipient.clone(), 250);
+    let settlement_id = AnonymousTipping::send_tip(env.clone(), sender.clone(), recipient.clone(), token.clone(), 250);
     let after_events: std::vec::Vec<_> = env.events().all().collect();
 
     assert_eq!(settlement_id, 1);
     assert_eq!(AnonymousTipping::latest_settlement_nonce(env.clone()), 1);
-    assert_eq!(AnonymousTipping::get_tips(env.clone(), recipient), 250);
+    assert_eq!(AnonymousTipping::get_tips(env.clone(), recipient, token), 250);
     assert_eq!(after_events.len(), before_events.len() + 1);
 }
 
 #[test]
 fn anonymous_tip_missing_proof_path_still_settles() {
     let env = Env::default();
+    let sender = Address::generate(&env);
     let recipient = Address::generate(&env);
-    AnonymousTipping::init(env.clone());
+    let token = Address::generate(&env);
+    AnonymousTipping::init(env.clone(), token.clone());
 
-    let first = AnonymousTipping::send_tip(env.clone(), recipient.clone(), 100);
-    let second = AnonymousTipping::send_tip(env.clone(), recipient.clone(), 50);
+    let first = AnonymousTipping::send_tip(env.clone(), sender.clone(), recipient.clone(), token.clone(), 100);
+    let second = AnonymousTipping::send_tip(env.clone(), sender.clone(), recipient.clone(), token.clone(), 50);
 
     assert_eq!(first, 1);
     assert_eq!(second, 2);
     assert_eq!(AnonymousTipping::latest_settlement_nonce(env.clone()), 2);
-    assert_eq!(AnonymousTipping::get_tips(env, recipient), 150);
+    assert_eq!(AnonymousTipping::get_tips(env, recipient, token), 150);
 }
@@ -1,4 +1,5 @@
-//! Storage compatibility tests for confession-anchor and anonymous-tipping contracts.
+//! Storage compatibility tests for confession-anchor, confession-registry and
+//! anonymous-tipping contracts.
 //!
 //! These tests pin the critical persisted state that backend consumers depend
 //! on so that a breaking storage layout change is caught before merge.  Each
@@ -11,9 +12,14 @@
 
 extern crate std;
 
-use anonymous_tipping::{AnonymousTipping, AnonymousTippingClient};
+use anonymous_tipping::{testutils::tipper, AnonymousTipping, AnonymousTippingClient};
 use confession_anchor::{ConfessionAnchor, ConfessionAnchorClient};
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String as SorobanString};
+use confession_registry::{
+    ConfessionRegistry, ConfessionRegistryClient, ConfessionStatus, DataKey, LegacyConfession,
+};
+use soroban_sdk::{
+    testutils::Address as _, vec, Address, BytesN, Env, String as SorobanString, Vec,
+};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Helpers
@@ -45,11 +51,66 @@ fn tipping_pair() -> (
     env.mock_all_auths();
     let id = env.register(AnonymousTipping, ());
     let pre = AnonymousTippingClient::new(&env, &id);
-    pre.init();
+    let token_admin = Address::generate(&env);
+    pre.init(
+        &env.register_stellar_asset_contract_v2(token_admin)
+            .address(),
+    );
     let post = AnonymousTippingClient::new(&env, &id);
     (env, pre, post)
 }
 
+fn registry(env: &Env) -> ConfessionRegistryClient<'static> {
+    env.mock_all_auths();
+    let id = env.register(ConfessionRegistry, ());
+    let client = ConfessionRegistryClient::new(env, &id);
+    client.initialize(&Address::generate(env));
+    client
+}
+
+/// Rewrite `author`'s bucketed index into the pre-bucket layout: a single
+/// `DataKey::AuthorConfessions` vector in instance storage.
+fn downgrade_author_index(env: &Env, client: &ConfessionRegistryClient, author: &Address) {
+    let ids = client.list_author_confessions(author, &None, &50).items;
+    env.as_contract(&client.address, || {
+        let persistent = env.storage().persistent();
+        persistent.remove(&DataKey::AuthorBucket(author.clone(), 0));
+        persistent.remove(&DataKey::AuthorConfessionCount(author.clone()));
+        for id in ids.iter() {
+            persistent.remove(&DataKey::AuthorIndexPos(id));
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::AuthorConfessions(author.clone()), &ids);
+    });
+}
+
+/// Store confession `id` by `author` in the pre-1.0 record layout, as an
+/// upgraded deployment would still hold it.
+fn write_legacy_confession(
+    env: &Env,
+    client: &ConfessionRegistryClient,
+    author: &Address,
+    id: u64,
+) -> BytesN<32> {
+    let hash = sample_hash(env, id as u8);
+    env.as_contract(&client.address, || {
+        let storage = env.storage().instance();
+        let record = LegacyConfession {
+            id,
+            author: author.clone(),
+            content_hash: hash.clone(),
+            created_at: 1_000 * id,
+            updated_at: 0,
+            status: ConfessionStatus::Active,
+        };
+        storage.set(&DataKey::Confession(id), &record);
+        storage.set(&DataKey::HashIndex(hash.clone()), &id);
+        storage.set(&DataKey::NextId, &(id + 1));
+    });
+    hash
+}
+
 fn sample_hash(env: &Env, seed: u8) -> BytesN<32> {
     BytesN::from_array(env, &[seed; 32])
 }
@@ -179,6 +240,120 @@ fn anchor_duplicate_protection_preserved_after_upgrade() {
     );
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// confession-registry storage compat
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// `migrate_author_index` moves a legacy instance-storage index into the
+/// persistent buckets, in order, and is a no-op the second time.
+#[test]
+fn registry_legacy_author_index_migrates_into_buckets() {
+    let env = Env::default();
+    let client = registry(&env);
+    let author = Address::generate(&env);
+    for seed in 1..=3u8 {
+        client.create_confession(&author, &sample_hash(&env, seed), &0, &None);
+    }
+    downgrade_author_index(&env, &client, &author);
+    assert_eq!(client.get_author_confession_count(&author), 0);
+
+    assert_eq!(client.migrate_author_index(&author), 3);
+    assert_eq!(client.migrate_author_index(&author), 0);
+
+    assert_eq!(client.get_author_confession_count(&author), 3);
+    let expected: Vec<u64> = vec![&env, 1, 2, 3];
+    assert_eq!(
+        client.list_author_confessions(&author, &None, &50).items,
+        expected
+    );
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&DataKey::AuthorConfessions(author.clone())));
+    });
+}
+
+/// A write to a legacy author's index folds the legacy IDs in first, so they
+/// stay ahead of confessions created after the upgrade.
+#[test]
+fn registry_legacy_author_index_migrates_on_first_write() {
+    let env = Env::default();
+    let client = registry(&env);
+    let author = Address::generate(&env);
+    for seed in 1..=2u8 {
+        client.create_confession(&author, &sample_hash(&env, seed), &0, &None);
+    }
+    downgrade_author_index(&env, &client, &author);
+
+    let id = client.create_confession(&author, &sample_hash(&env, 3), &0, &None);
+
+    assert_eq!(client.get_author_confession_count(&author), 3);
+    let expected: Vec<u64> = vec![&env, 1, 2, id];
+    assert_eq!(
+        client.list_author_confessions(&author, &None, &50).items,
+        expected
+    );
+    assert_eq!(client.migrate_author_index(&author), 0);
+}
+
+/// A record in the pre-1.0 layout still decodes, and writes to it store the
+/// current layout.
+#[test]
+fn registry_legacy_confession_record_is_readable_and_writable() {
+    let env = Env::default();
+    let client = registry(&env);
+    let author = Address::generate(&env);
+    let hash = write_legacy_confession(&env, &client, &author, 1);
+
+    let confession = client.get_confession(&1);
+    assert_eq!(confession.author, Some(author.clone()));
+    assert_eq!(confession.author_commitment, None);
+    assert_eq!(confession.content_hash, hash);
+    assert_eq!(confession.created_at, 1_000);
+    assert_eq!(confession.expires_at, None);
+    assert!(confession.labels.is_empty());
+    assert_eq!(client.get_by_hash(&hash), 1);
+
+    client.delete_confession(&author, &1, &2_000, &None);
+    assert_eq!(client.get_confession(&1).status, ConfessionStatus::Deleted);
+    assert_eq!(
+        client
+            .migrate_confessions(&client.get_owner(), &None, &10)
+            .migrated,
+        0
+    );
+}
+
+/// `migrate_confessions` rewrites legacy records in bounded batches and
+/// leaves current records alone.
+#[test]
+fn registry_migrate_confessions_rewrites_legacy_records() {
+    let env = Env::default();
+    let client = registry(&env);
+    let owner = client.get_owner();
+    let author = Address::generate(&env);
+    for id in 1..=3 {
+        write_legacy_confession(&env, &client, &author, id);
+    }
+    let current = client.create_confession(&author, &sample_hash(&env, 0x40), &0, &None);
+    assert_eq!(current, 4);
+
+    let first = client.migrate_confessions(&owner, &None, &2);
+    assert_eq!(first.migrated, 2);
+    assert_eq!(first.next_cursor, Some(2));
+
+    let rest = client.migrate_confessions(&owner, &first.next_cursor, &10);
+    assert_eq!(rest.migrated, 1);
+    assert_eq!(rest.next_cursor, None);
+
+    let again = client.migrate_confessions(&owner, &None, &10);
+    assert_eq!(again.migrated, 0);
+    for id in 1..=3u64 {
+        assert_eq!(client.get_confession(&id).author, Some(author.clone()));
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // anonymous-tipping storage compat
 // ─────────────────────────────────────────────────────────────────────────────
@@ -188,10 +363,12 @@ fn anchor_duplicate_protection_preserved_after_upgrade() {
 #[test]
 fn tipping_settlement_nonce_survives_upgrade() {
     let (env, pre, post) = tipping_pair();
+    let token = pre.get_token().unwrap();
+    let tipper = tipper(&env, &pre);
     let recipient = Address::generate(&env);
 
-    let id1 = pre.send_tip(&recipient, &100i128);
-    let id2 = pre.send_tip(&recipient, &200i128);
+    let id1 = pre.send_tip(&tipper, &recipient, &token, &100i128);
+    let id2 = pre.send_tip(&tipper, &recipient, &token, &200i128);
     assert_eq!(id1, 1);
     assert_eq!(id2, 2);
 
@@ -207,21 +384,23 @@ fn tipping_settlement_nonce_survives_upgrade() {
 #[test]
 fn tipping_recipient_totals_survive_upgrade() {
     let (env, pre, post) = tipping_pair();
+    let token = pre.get_token().unwrap();
+    let tipper = tipper(&env, &pre);
 
     let alice = Address::generate(&env);
     let bob = Address::generate(&env);
 
-    pre.send_tip(&alice, &500i128);
-    pre.send_tip(&alice, &250i128);
-    pre.send_tip(&bob, &1000i128);
+    pre.send_tip(&tipper, &alice, &token, &500i128);
+    pre.send_tip(&tipper, &alice, &token, &250i128);
+    pre.send_tip(&tipper, &bob, &token, &1000i128);
 
     assert_eq!(
-        post.get_tips(&alice),
+        post.get_tips(&alice, &token),
         750i128,
         "alice total must survive upgrade"
     );
     assert_eq!(
-        post.get_tips(&bob),
+        post.get_tips(&bob, &token),
         1000i128,
         "bob total must survive upgrade"
     );
@@ -270,6 +449,8 @@ fn tipping_rate_limit_config_survives_upgrade() {
 #[test]
 fn tipping_fresh_state_survives_upgrade() {
     let (env, pre, post) = tipping_pair();
+    let token = post.get_token().unwrap();
+    let tipper = tipper(&env, &post);
 
     assert_eq!(pre.latest_settlement_nonce(), 0);
     assert!(!pre.is_paused());
@@ -279,6 +460,6 @@ fn tipping_fresh_state_survives_upgrade() {
 
     // Post-upgrade first tip must still produce settlement_id 1.
     let recipient = Address::generate(&env);
-    let id = post.send_tip(&recipient, &1i128);
+    let id = post.send_tip(&tipper, &recipient, &token, &1i128);
     assert_eq!(id, 1);
 }